@@ -0,0 +1,144 @@
+/*!
+Recorded answers for real inputs, so refactors can be checked against them.
+
+Each day's answers live in `answers/NN.toml` (or `$AOC_ANSWERS_DIR/NN.toml`),
+next to the inputs they belong to:
+
+```toml
+part1 = 288
+part2 = "71503"
+```
+
+Either part may be left out, and answers can be numbers or strings.
+*/
+
+use std::io;
+use std::path::PathBuf;
+
+use crate::{ParseError, input};
+
+/// The environment variable that overrides the answers directory.
+pub const ANSWERS_DIR_VAR: &str = "AOC_ANSWERS_DIR";
+
+/// The directory answers are read from when no `AOC_ANSWERS_DIR` is set.
+pub const DEFAULT_ANSWERS_DIR: &str = "answers";
+
+/// The answers file for a day, like `answers/05.toml`.
+#[must_use]
+pub fn default_path(number: u32) -> PathBuf {
+    input::env_dir(ANSWERS_DIR_VAR, DEFAULT_ANSWERS_DIR).join(format!("{number:02}.toml"))
+}
+
+/// The recorded answers for one day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Answers {
+    /// Read answers from TOML text.
+    ///
+    /// # Errors
+    ///
+    /// If the text is not valid TOML, or an answer is not a number or string.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let table: toml::Table = text.parse().map_err(|err: toml::de::Error| {
+            let message = err.message().to_string();
+            match err.span() {
+                Some(span) => ParseError::at(&text[span], message),
+                None => ParseError::new(message),
+            }
+            .locate(text)
+        })?;
+        let answer = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(toml::Value::String(value)) => Ok(Some(value.clone())),
+            Some(toml::Value::Integer(value)) => Ok(Some(value.to_string())),
+            Some(_) => Err(ParseError::new(format!(
+                "{key} should be a number or a string"
+            ))),
+        };
+        Ok(Self {
+            part1: answer("part1")?,
+            part2: answer("part2")?,
+        })
+    }
+
+    /// Load the answers for a day, or `None` if there is no answers file.
+    ///
+    /// # Errors
+    ///
+    /// If the file exists but can't be read or parsed.
+    pub fn load(number: u32) -> io::Result<Option<Self>> {
+        match std::fs::read_to_string(default_path(number)) {
+            Ok(text) => Self::parse(&text)
+                .map(Some)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// The recorded answer for a part (1 or 2), if there is one.
+    #[must_use]
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compare a computed answer with the recorded one.
+    #[must_use]
+    pub fn check(&self, part: u8, answer: &str) -> Outcome {
+        match self.part(part) {
+            None => Outcome::Missing,
+            Some(expected) if expected == answer => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// The result of checking one part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The answer matches.
+    Pass,
+    /// The answer differs from the recorded one.
+    Fail { expected: String },
+    /// There is no recorded answer.
+    Missing,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part1 = 288\npart2 = \"71503\"\n").unwrap();
+        assert_eq!(answers.part(1), Some("288"));
+        assert_eq!(answers.part(2), Some("71503"));
+        assert_eq!(answers.check(1, "288"), Outcome::Pass);
+        assert_eq!(
+            answers.check(2, "1"),
+            Outcome::Fail {
+                expected: "71503".to_string()
+            }
+        );
+
+        let answers = Answers::parse("part1 = 1").unwrap();
+        assert_eq!(answers.check(2, "1"), Outcome::Missing);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Answers::parse("part1 = [1]").is_err());
+        let err = Answers::parse("part1 = 1\npart2 = \n").unwrap_err();
+        assert_eq!(err.position().map(|(line, _)| line), Some(2));
+    }
+}
@@ -0,0 +1,208 @@
+/*!
+Benchmarking the days: parse once, run each part several times, and keep the
+median. Results can be saved as a baseline (a small TOML file of nanoseconds
+per day) and compared against later, so slowdowns stand out.
+
+```toml
+[05]
+parse = 41000
+part1 = 18000
+part2 = 2100000000
+```
+*/
+
+use core::fmt::{self, Display, Formatter};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::ParseError;
+use crate::days::{Day, SolveError, solve_caught};
+
+/// Median timings for one day. Parts the day doesn't have are `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    /// The timing for a part (1 or 2).
+    #[must_use]
+    pub const fn part(&self, part: u8) -> Option<Duration> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+/// The median of some timings (the lower one for an even count).
+///
+/// # Panics
+///
+/// If there are no timings.
+#[must_use]
+pub fn median(times: &mut [Duration]) -> Duration {
+    assert!(!times.is_empty(), "No timings to take the median of");
+    times.sort_unstable();
+    times[(times.len() - 1) / 2]
+}
+
+/// Why a day couldn't be timed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BenchError {
+    Parse(ParseError),
+    /// A part failed (or panicked), so its time would mean nothing.
+    Solve(u8, SolveError),
+}
+
+impl Display for BenchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "could not parse input: {err}"),
+            Self::Solve(part, err) => write!(f, "part {part}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+/// Parse the input once, then run every part `runs` times.
+///
+/// # Errors
+///
+/// If the input can't be parsed, or a part fails or panics.
+pub fn bench(day: &Day, text: &str, runs: usize) -> Result<Timings, BenchError> {
+    let start = Instant::now();
+    let input = (day.parse)(text).map_err(BenchError::Parse)?;
+    let parse = start.elapsed();
+
+    let time_part = |part: u8| {
+        if !day.has_part(part) {
+            return Ok(None);
+        }
+        let mut times = Vec::new();
+        for _ in 0..runs.max(1) {
+            let start = Instant::now();
+            let answer = std::hint::black_box(solve_caught(&*input, part));
+            times.push(start.elapsed());
+            answer.map_err(|err| BenchError::Solve(part, err))?;
+        }
+        Ok(Some(median(&mut times)))
+    };
+    Ok(Timings {
+        parse,
+        part1: time_part(1)?,
+        part2: time_part(2)?,
+    })
+}
+
+/// Saved timings, by day name.
+pub type Baseline = BTreeMap<String, Timings>;
+
+fn nanos(time: Duration) -> toml::Value {
+    toml::Value::Integer(i64::try_from(time.as_nanos()).unwrap_or(i64::MAX))
+}
+
+fn duration(value: Option<&toml::Value>) -> Option<Duration> {
+    Some(Duration::from_nanos(value?.as_integer()?.try_into().ok()?))
+}
+
+/// Write timings out as a baseline file.
+///
+/// # Errors
+///
+/// If the file can't be written.
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let mut table = toml::Table::new();
+    for (name, timings) in baseline {
+        let mut day = toml::Table::new();
+        day.insert("parse".to_string(), nanos(timings.parse));
+        for (key, time) in [("part1", timings.part1), ("part2", timings.part2)] {
+            if let Some(time) = time {
+                day.insert(key.to_string(), nanos(time));
+            }
+        }
+        table.insert(name.clone(), toml::Value::Table(day));
+    }
+    std::fs::write(path, table.to_string())
+}
+
+/// Read a baseline file written by [`save_baseline`].
+///
+/// # Errors
+///
+/// If the file can't be read or is not a baseline.
+pub fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    let text = std::fs::read_to_string(path)?;
+    let table: toml::Table = text
+        .parse()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    Ok(table
+        .iter()
+        .filter_map(|(name, day)| {
+            let day = day.as_table()?;
+            let timings = Timings {
+                parse: duration(day.get("parse"))?,
+                part1: duration(day.get("part1")),
+                part2: duration(day.get("part2")),
+            };
+            Some((name.clone(), timings))
+        })
+        .collect())
+}
+
+/// Format a timing, with the change from the baseline if there is one.
+#[must_use]
+pub fn compare(time: Duration, baseline: Option<Duration>) -> String {
+    match baseline {
+        Some(old) if !old.is_zero() => {
+            let change = (time.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+            format!("{time:.2?} ({change:+.0}%)")
+        }
+        _ => format!("{time:.2?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(&mut [ms(3), ms(1), ms(2)]), ms(2));
+        assert_eq!(median(&mut [ms(4), ms(1), ms(3), ms(2)]), ms(2));
+        assert_eq!(median(&mut [ms(7)]), ms(7));
+    }
+
+    #[test]
+    fn test_compare() {
+        let ms = Duration::from_millis;
+        assert_eq!(compare(ms(2), None), "2.00ms");
+        assert_eq!(compare(ms(3), Some(ms(2))), "3.00ms (+50%)");
+        assert_eq!(compare(ms(1), Some(ms(2))), "1.00ms (-50%)");
+    }
+
+    #[test]
+    fn test_baseline() {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.toml", std::process::id()));
+        let mut baseline = Baseline::new();
+        baseline.insert(
+            "25".to_string(),
+            Timings {
+                parse: Duration::from_micros(5),
+                part1: Some(Duration::from_millis(2)),
+                part2: None,
+            },
+        );
+        save_baseline(&path, &baseline).unwrap();
+        let loaded = load_baseline(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, baseline);
+    }
+}
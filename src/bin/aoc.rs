@@ -0,0 +1,479 @@
+/*!
+# Advent of Code 2023 runner
+
+Runs any or all of the days in one process, timing each part.
+
+```bash
+cargo run -r --bin aoc -- run 17 --part 2
+cargo run -r --bin aoc -- run all
+cargo run -r --bin aoc -- run 05 --input other/05.txt
+cargo run -r --bin aoc -- run all --format json
+cargo run -r --bin aoc -- verify
+cargo run -r --bin aoc -- bench all --runs 20 --save bench.toml
+cargo run --bin aoc -- new-day 7 "Camel Cards" --variant trait
+```
+
+Diagnostics from the days (grids, graphs, and the like) are logged to stderr;
+`-v` shows progress and `-vv` the debug pictures, while `-q` leaves only errors.
+
+Inputs are read from `input/NN.txt`, or from `$AOC_INPUT_DIR/NN.txt` if set.
+With `--format json`, each answer is printed as a JSON line instead (see
+[`aoc2023::report`]). `verify` checks the answers against `answers/NN.toml` (see
+[`aoc2023::answers`]).
+*/
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use aoc2023::answers::{Answers, Outcome};
+use aoc2023::bench::{self, Baseline};
+use aoc2023::days::{self, Day, solve_caught};
+use aoc2023::input;
+use aoc2023::report::{Failure, Record};
+use aoc2023::scaffold::{EXAMPLE_ANSWERS, NewDay};
+
+/// Run the Advent of Code 2023 solutions.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Show diagnostics from the days (`-v` for progress, `-vv` for grids and graphs)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Only show errors (overrides `--verbose`)
+    #[arg(short, long, global = true)]
+    quiet: bool,
+}
+
+impl Cli {
+    /// The log level for the verbosity flags; warnings and errors by default.
+    const fn log_level(&self) -> log::LevelFilter {
+        match (self.quiet, self.verbose) {
+            (true, _) => log::LevelFilter::Error,
+            (false, 0) => log::LevelFilter::Warn,
+            (false, 1) => log::LevelFilter::Info,
+            (false, 2) => log::LevelFilter::Debug,
+            (false, _) => log::LevelFilter::Trace,
+        }
+    }
+}
+
+/// How answers are printed.
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `Day 05 part 1: 35 (18.00µs)`, and a total
+    #[default]
+    Text,
+    /// One `{day, part, answer, elapsed}` JSON object per line
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (`7`, `07`, `07-trait`), or `all` of them
+    Run {
+        /// The day to run, or `all`
+        day: String,
+
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read this input file instead (`-` for stdin); only for a single day
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+
+        /// How to print the answers
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Check days against their recorded answers in `answers/NN.toml`
+    Verify {
+        /// The day to check, or `all`
+        #[arg(default_value = "all")]
+        day: String,
+    },
+    /// Time parsing and each part, reporting the median of several runs
+    Bench {
+        /// The day to benchmark, or `all`
+        #[arg(default_value = "all")]
+        day: String,
+
+        /// How many times to run each part
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+
+        /// Compare against timings saved with `--save`
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// Save the timings to this file
+        #[arg(short, long)]
+        save: Option<PathBuf>,
+    },
+    /// Create `src/days/dayNN.rs` from the template, register it, and add an example to fill in
+    NewDay {
+        /// The puzzle day, 1-25
+        day: u32,
+
+        /// The puzzle title
+        title: String,
+
+        /// Name an alternate implementation, like `trait` for `07-trait`
+        #[arg(long)]
+        variant: Option<String>,
+    },
+}
+
+/// Read an input file, reporting any error against `name`.
+fn read_input(name: &str, path: &Path) -> Option<String> {
+    match input::read(path) {
+        Ok(text) => Some(text),
+        Err(err) => {
+            eprintln!("Day {name}: could not read {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// Parse a day's input once, then run the requested parts, printing answers
+/// and timings in the given format. Returns the total time spent (parsing included), or `None`
+/// if the input could not be parsed or a part has no answer.
+fn run_day(day: &Day, text: &str, parts: &[u8], format: Format) -> Option<Duration> {
+    let start = Instant::now();
+    let input = match (day.parse)(text) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: could not parse input: {err}", day.name);
+            return None;
+        }
+    };
+    let mut total = start.elapsed();
+    let mut failed = false;
+    for &part in parts.iter().filter(|&&part| day.has_part(part)) {
+        let start = Instant::now();
+        let answer = solve_caught(&*input, part);
+        let elapsed = start.elapsed();
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(err) => {
+                failed = true;
+                match format {
+                    Format::Text => eprintln!("Day {} part {part}: {err}", day.name),
+                    Format::Json => {
+                        let error = err.to_string();
+                        let failure = Failure {
+                            day: day.name,
+                            part,
+                            error: &error,
+                        };
+                        println!("{failure}");
+                    }
+                }
+                continue;
+            }
+        };
+        total += elapsed;
+        match format {
+            Format::Text => println!("Day {} part {part}: {answer} ({elapsed:.2?})", day.name),
+            Format::Json => {
+                let record = Record {
+                    day: day.name,
+                    part,
+                    answer: &answer,
+                    elapsed,
+                };
+                println!("{record}");
+            }
+        }
+    }
+    (!failed).then_some(total)
+}
+
+/// Tally of verified parts.
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+/// Check every part of a day against its recorded answers. Days without an
+/// input are skipped; unreadable answers or input count as a failure.
+fn verify_day(day: &Day, tally: &mut Tally) {
+    let path = day.input_path();
+    if !path.exists() {
+        println!("Day {}: skipped, no input at {}", day.name, path.display());
+        return;
+    }
+    let answers = match Answers::load(day.number) {
+        Ok(answers) => answers.unwrap_or_default(),
+        Err(err) => {
+            eprintln!("Day {}: could not read answers: {err}", day.name);
+            tally.failed += day.parts.len();
+            return;
+        }
+    };
+    let parsed = read_input(day.name, &path).and_then(|text| match (day.parse)(&text) {
+        Ok(input) => Some(input),
+        Err(err) => {
+            eprintln!("Day {}: could not parse input: {err}", day.name);
+            None
+        }
+    });
+    let Some(parsed) = parsed else {
+        tally.failed += day.parts.len();
+        return;
+    };
+
+    for &part in day.parts {
+        let answer = match solve_caught(&*parsed, part) {
+            Ok(answer) => answer,
+            Err(err) => {
+                tally.failed += 1;
+                println!("Day {} part {part}: FAIL, {err}", day.name);
+                continue;
+            }
+        };
+        match answers.check(part, &answer) {
+            Outcome::Pass => {
+                tally.passed += 1;
+                println!("Day {} part {part}: pass", day.name);
+            }
+            Outcome::Fail { expected } => {
+                tally.failed += 1;
+                println!(
+                    "Day {} part {part}: FAIL, got {answer}, expected {expected}",
+                    day.name
+                );
+            }
+            Outcome::Missing => {
+                tally.missing += 1;
+                println!("Day {} part {part}: missing (got {answer})", day.name);
+            }
+        }
+    }
+}
+
+fn verify(day: &str) -> ExitCode {
+    let selected = days::select(day);
+    if selected.is_empty() {
+        eprintln!("No day matches {day:?}");
+        return ExitCode::FAILURE;
+    }
+
+    let mut tally = Tally::default();
+    for day in &selected {
+        verify_day(day, &mut tally);
+    }
+    println!(
+        "{} passed, {} failed, {} missing",
+        tally.passed, tally.failed, tally.missing
+    );
+
+    if tally.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(day: &str, runs: u32, baseline: Option<&Path>, save: Option<&Path>) -> ExitCode {
+    let selected = days::select(day);
+    if selected.is_empty() {
+        eprintln!("No day matches {day:?}");
+        return ExitCode::FAILURE;
+    }
+    let baseline = match baseline.map(bench::load_baseline).transpose() {
+        Ok(baseline) => baseline.unwrap_or_default(),
+        Err(err) => {
+            eprintln!("Could not read the baseline: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    let mut results = Baseline::new();
+    println!(
+        "{:<10} {:>20} {:>20} {:>20}",
+        "Day", "parse", "part 1", "part 2"
+    );
+    for day in &selected {
+        let Some(text) = read_input(day.name, &day.input_path()) else {
+            failed = true;
+            continue;
+        };
+        let timings = match bench::bench(day, &text, runs as usize) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("Day {}: {err}", day.name);
+                failed = true;
+                continue;
+            }
+        };
+        let old = baseline.get(day.name);
+        let part = |part: u8| {
+            timings.part(part).map_or_else(
+                || "-".to_string(),
+                |time| bench::compare(time, old.and_then(|old| old.part(part))),
+            )
+        };
+        println!(
+            "{:<10} {:>20} {:>20} {:>20}",
+            day.name,
+            bench::compare(timings.parse, old.map(|old| old.parse)),
+            part(1),
+            part(2)
+        );
+        results.insert(day.name.to_string(), timings);
+    }
+
+    if let Some(path) = save
+        && let Err(err) = bench::save_baseline(path, &results)
+    {
+        eprintln!("Could not save {}: {err}", path.display());
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Create a new day in this source tree, refusing to overwrite anything.
+fn new_day(day: u32, title: &str, variant: Option<&str>) -> Result<(), String> {
+    let new = NewDay::new(day, title, variant)?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let path = root.join(new.path());
+    if path.exists() {
+        return Err(format!("{} already exists", path.display()));
+    }
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+    };
+    let template = read(&root.join("template/main.rs"))?;
+    let days_path = root.join("src/days.rs");
+    let days = new.register(&read(&days_path)?)?;
+
+    let write = |path: &Path, text: &str| {
+        std::fs::write(path, text).map_err(|err| format!("{}: {err}", path.display()))
+    };
+    write(&path, &new.render(&template))?;
+    write(&days_path, &days)?;
+    println!("Created {} as day {:?}", new.path(), new.name());
+
+    // A variant shares the day's examples, so only a new day gets them.
+    let example = root.join(new.example_path());
+    if !example.exists() {
+        let dir = example.parent().unwrap_or(root);
+        std::fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        write(&example, "")?;
+        write(&example.with_extension("toml"), EXAMPLE_ANSWERS)?;
+        println!("Its example goes in {}", new.example_path());
+    }
+    println!("Its input goes in {}", input::default_path(day).display());
+    Ok(())
+}
+
+fn run(day: &str, part: Option<u8>, input: Option<&Path>, format: Format) -> ExitCode {
+    let selected = days::select(day);
+    if selected.is_empty() {
+        eprintln!("No day matches {day:?}");
+        return ExitCode::FAILURE;
+    }
+    let parts = part.map_or_else(|| vec![1, 2], |p| vec![p]);
+
+    // An explicit input (maybe stdin) is read once, so it can only be
+    // shared between implementations of the same day.
+    let shared = match input {
+        Some(path) => {
+            if selected.iter().any(|d| d.number != selected[0].number) {
+                eprintln!("--input can only be used with a single day");
+                return ExitCode::FAILURE;
+            }
+            let Some(text) = read_input(day, path) else {
+                return ExitCode::FAILURE;
+            };
+            Some(text)
+        }
+        None => None,
+    };
+
+    let everything = day == "all";
+    let mut failed = false;
+    let mut total = Duration::ZERO;
+    for day in &selected {
+        // Asking one day for a part it doesn't have is a mistake, but `all`
+        // just skips it.
+        if let Some(part) = part
+            && !day.has_part(part)
+        {
+            if !everything {
+                eprintln!("Day {} has no part {part}", day.name);
+                failed = true;
+            }
+            continue;
+        }
+        let text = shared
+            .clone()
+            .or_else(|| read_input(day.name, &day.input_path()));
+        match text.and_then(|text| run_day(day, &text, &parts, format)) {
+            Some(elapsed) => total += elapsed,
+            None => failed = true,
+        }
+    }
+    if selected.len() > 1 && format == Format::Text {
+        println!("Total: {total:.2?}");
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    // Diagnostics go to stderr, so stdout only has answers. `RUST_LOG` can
+    // still tune individual modules.
+    env_logger::Builder::new()
+        .filter_level(cli.log_level())
+        .parse_default_env()
+        .init();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(&day, part, input.as_deref(), format),
+        Command::Verify { day } => verify(&day),
+        Command::Bench {
+            day,
+            runs,
+            baseline,
+            save,
+        } => bench(&day, runs, baseline.as_deref(), save.as_deref()),
+        Command::NewDay {
+            day,
+            title,
+            variant,
+        } => match new_day(day, &title, variant.as_deref()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
@@ -0,0 +1,138 @@
+/*!
+Finding cycles in a state machine that is stepped over and over, so step one
+billion can be looked up instead of simulated.
+
+States are recorded as they are visited, until one repeats. A key function
+picks out the part of a state that matters for repeats, which also allows
+states that aren't hashable themselves (or that carry extra data, like counts
+from the last step).
+
+```ignore
+let history = History::find(grid, spin);
+let grid = history.state_at(1_000_000_000).unwrap();
+```
+*/
+
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// Where a cycle starts, and how many steps it takes to come back around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step that is part of the cycle.
+    pub start: usize,
+    /// The length of the cycle, at least 1.
+    pub len: usize,
+}
+
+/// The states visited from an initial state (step 0), and the cycle they fell
+/// into, if one was found.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    states: Vec<S>,
+    cycle: Option<Cycle>,
+}
+
+impl<S: Clone + Eq + Hash> History<S> {
+    /// Step from `initial` until a state repeats.
+    ///
+    /// This only returns once a cycle is found, so the state space must be
+    /// finite.
+    pub fn find(initial: S, step: impl FnMut(&S) -> S) -> Self {
+        Self::find_by_key(initial, step, Clone::clone, usize::MAX)
+    }
+}
+
+impl<S> History<S> {
+    /// Step from `initial` until the `key` of a state repeats, or `limit`
+    /// steps have been taken.
+    ///
+    /// The key should capture everything that affects later steps: past the
+    /// recorded states, [`History::state_at`] gives the recorded state with
+    /// the same key.
+    pub fn find_by_key<K: Eq + Hash>(
+        initial: S,
+        mut step: impl FnMut(&S) -> S,
+        mut key: impl FnMut(&S) -> K,
+        limit: usize,
+    ) -> Self {
+        let mut seen = HashMap::new();
+        let mut states = vec![initial];
+        loop {
+            let n = states.len() - 1;
+            let current = &states[n];
+            if let Some(start) = seen.insert(key(current), n) {
+                // The repeat is the same as `start`, so it isn't kept.
+                states.pop();
+                let cycle = Cycle {
+                    start,
+                    len: n - start,
+                };
+                return Self {
+                    states,
+                    cycle: Some(cycle),
+                };
+            }
+            if n >= limit {
+                return Self {
+                    states,
+                    cycle: None,
+                };
+            }
+            let next = step(current);
+            states.push(next);
+        }
+    }
+
+    /// The cycle, or `None` if the limit was reached first.
+    #[must_use]
+    pub const fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// Every state visited, starting with the initial one.
+    #[must_use]
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// The state after `n` steps, or `None` if that is past the limit and no
+    /// cycle was found.
+    #[must_use]
+    pub fn state_at(&self, n: usize) -> Option<&S> {
+        if let Some(state) = self.states.get(n) {
+            return Some(state);
+        }
+        let Cycle { start, len } = self.cycle?;
+        self.states.get(start + (n - start) % len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let history = History::find(0, |&n| if n == 4 { 2 } else { n + 1 });
+        assert_eq!(history.cycle(), Some(Cycle { start: 2, len: 3 }));
+        assert_eq!(history.states(), [0, 1, 2, 3, 4]);
+        assert_eq!(history.state_at(1), Some(&1));
+        assert_eq!(history.state_at(5), Some(&2));
+        assert_eq!(history.state_at(1_000_000_000), Some(&4));
+    }
+
+    #[test]
+    fn test_find_by_key() {
+        // Only the last digit counts, so step 12 looks like step 2.
+        let history = History::find_by_key(0, |&n| n + 3, |&n| n % 10, 100);
+        assert_eq!(history.cycle(), Some(Cycle { start: 0, len: 10 }));
+        assert_eq!(history.state_at(12), Some(&6));
+
+        let history = History::find_by_key(0, |&n| n + 1, |&n| n, 5);
+        assert_eq!(history.cycle(), None);
+        assert_eq!(history.state_at(5), Some(&5));
+        assert_eq!(history.state_at(6), None);
+    }
+}
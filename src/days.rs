@@ -0,0 +1,277 @@
+/*!
+The solutions for each day, and a registry so they can be run from one place.
+
+Each day lives in its own module, `dayNN`. The [`DAYS`] table links every day's
+parts so the `aoc` binary can run one, several, or all of them.
+*/
+
+pub mod day01;
+pub mod day01b;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day07_trait;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use core::fmt::{self, Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use crate::{Answer, ParseError, PartTwo, Solution, input};
+
+/// Why a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The day doesn't have this part (day 25 only has one).
+    NoPart(u8),
+    /// The part ran, but couldn't find an answer in this input.
+    Failed(String),
+    /// The part panicked (see [`solve_caught`]).
+    Panicked(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoPart(part) => write!(f, "there is no part {part}"),
+            Self::Failed(reason) => f.write_str(reason),
+            Self::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A day's parsed input, with the answers erased to strings so every day can
+/// sit in the same table.
+pub trait Parsed {
+    /// Solve one part (1 or 2) of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// If the day doesn't have that part, or the part fails.
+    fn solve(&self, part: u8) -> Result<String, SolveError>;
+}
+
+/// A second part, with its answer erased to a string (see [`Answer`]).
+type PartFn<I> = fn(&I) -> Result<String, String>;
+
+/// Solve a part, turning a panic in the day into an error so that one broken
+/// day doesn't stop the others from running.
+///
+/// # Errors
+///
+/// If the part can't be solved, or panics.
+pub fn solve_caught(parsed: &dyn Parsed, part: u8) -> Result<String, SolveError> {
+    match panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part))) {
+        Ok(answer) => answer,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown reason");
+            Err(SolveError::Panicked(message.to_string()))
+        }
+    }
+}
+
+/// The parsed input of a [`Solution`], tied back to the solution that made it,
+/// and its second part if it has one.
+struct Input<S: Solution> {
+    input: S::Input,
+    part2: Option<PartFn<S::Input>>,
+}
+
+impl<S: Solution> Parsed for Input<S> {
+    fn solve(&self, part: u8) -> Result<String, SolveError> {
+        let answer = match (part, self.part2) {
+            (1, _) => S::part1(&self.input).into_answer(),
+            (2, Some(part2)) => part2(&self.input),
+            _ => return Err(SolveError::NoPart(part)),
+        };
+        answer.map_err(SolveError::Failed)
+    }
+}
+
+fn parse_with<S: Solution + 'static>(
+    text: &str,
+    part2: Option<PartFn<S::Input>>,
+) -> Result<Box<dyn Parsed>, ParseError> {
+    match S::parse(text) {
+        Ok(input) => Ok(Box::new(Input::<S> { input, part2 })),
+        Err(err) => Err(err.locate(text)),
+    }
+}
+
+fn parse<S: PartTwo + 'static>(text: &str) -> Result<Box<dyn Parsed>, ParseError> {
+    parse_with::<S>(text, Some(|input| S::part2(input).into_answer()))
+}
+
+fn parse_part1<S: Solution + 'static>(text: &str) -> Result<Box<dyn Parsed>, ParseError> {
+    parse_with::<S>(text, None)
+}
+
+/// A registered day. Alternate implementations (like `07-trait`) share the
+/// day number but have their own name.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    /// The puzzle day, 1-25.
+    pub number: u32,
+    /// The name used to select this day, like `05` or `07-trait`.
+    pub name: &'static str,
+    /// Parse the puzzle input, ready to solve either part. Errors are
+    /// already located in the text.
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>, ParseError>,
+    /// The parts this day has (day 25 has only one puzzle).
+    pub parts: &'static [u8],
+}
+
+impl Day {
+    /// Register a [`Solution`] with both parts under a day number and name.
+    #[must_use]
+    pub const fn new<S: PartTwo + 'static>(number: u32, name: &'static str) -> Self {
+        Self {
+            number,
+            name,
+            parse: parse::<S>,
+            parts: &[1, 2],
+        }
+    }
+
+    /// Register a [`Solution`] that only has a first part.
+    #[must_use]
+    pub const fn part1_only<S: Solution + 'static>(number: u32, name: &'static str) -> Self {
+        Self {
+            number,
+            name,
+            parse: parse_part1::<S>,
+            parts: &[1],
+        }
+    }
+
+    /// The input file for this day, in `AOC_INPUT_DIR` (or `input`).
+    #[must_use]
+    pub fn input_path(&self) -> PathBuf {
+        input::default_path(self.number)
+    }
+
+    /// Whether this day has the given part.
+    #[must_use]
+    pub fn has_part(&self, part: u8) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+/// Every day, in order.
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1, "01"),
+    Day::new::<day02::Day02>(2, "02"),
+    Day::new::<day03::Day03>(3, "03"),
+    Day::new::<day04::Day04>(4, "04"),
+    Day::new::<day05::Day05>(5, "05"),
+    Day::new::<day06::Day06>(6, "06"),
+    Day::new::<day07::Day07>(7, "07"),
+    Day::new::<day07_trait::Day07Trait>(7, "07-trait"),
+    Day::new::<day08::Day08>(8, "08"),
+    Day::new::<day09::Day09>(9, "09"),
+    Day::new::<day10::Day10>(10, "10"),
+    Day::new::<day11::Day11>(11, "11"),
+    Day::new::<day12::Day12>(12, "12"),
+    Day::new::<day13::Day13>(13, "13"),
+    Day::new::<day14::Day14>(14, "14"),
+    Day::new::<day15::Day15>(15, "15"),
+    Day::new::<day16::Day16>(16, "16"),
+    Day::new::<day17::Day17>(17, "17"),
+    Day::new::<day18::Day18>(18, "18"),
+    Day::new::<day19::Day19>(19, "19"),
+    Day::new::<day20::Day20>(20, "20"),
+    Day::new::<day21::Day21>(21, "21"),
+    Day::new::<day22::Day22>(22, "22"),
+    Day::new::<day23::Day23>(23, "23"),
+    Day::new::<day24::Day24>(24, "24"),
+    Day::part1_only::<day25::Day25>(25, "25"),
+];
+
+/// Look up days by name. `all` selects every day, a number like `7` or `07`
+/// selects that day (and any alternate implementations), and a full name like
+/// `07-trait` selects just that one.
+#[must_use]
+pub fn select(name: &str) -> Vec<&'static Day> {
+    if name == "all" {
+        return DAYS.iter().collect();
+    }
+    if let Ok(number) = name.parse::<u32>() {
+        return DAYS.iter().filter(|day| day.number == number).collect();
+    }
+    DAYS.iter().filter(|day| day.name == name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select("all").len(), DAYS.len());
+        assert_eq!(select("5").len(), 1);
+        assert_eq!(select("05")[0].name, "05");
+        assert_eq!(select("7").len(), 2);
+        assert_eq!(select("07-trait").len(), 1);
+        assert!(select("26").is_empty());
+        assert!(select("nope").is_empty());
+        assert!(select("24")[0].has_part(2));
+        assert_eq!(select("25")[0].parts, &[1]);
+    }
+
+    #[test]
+    fn test_missing_part() {
+        let text = crate::examples::load(25).unwrap().remove(0).text;
+        let input = (select("25")[0].parse)(&text).unwrap();
+        assert!(input.solve(1).is_ok());
+        assert_eq!(input.solve(2), Err(SolveError::NoPart(2)));
+        assert_eq!(input.solve(3), Err(SolveError::NoPart(3)));
+    }
+
+    struct Broken;
+
+    impl Parsed for Broken {
+        fn solve(&self, part: u8) -> Result<String, SolveError> {
+            match part {
+                1 => panic!("part {part} is broken"),
+                _ => panic!("no message"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_caught() {
+        let err = solve_caught(&Broken, 1).unwrap_err();
+        assert_eq!(err, SolveError::Panicked("part 1 is broken".to_string()));
+        assert_eq!(err.to_string(), "panicked: part 1 is broken");
+        assert_eq!(
+            solve_caught(&Broken, 2),
+            Err(SolveError::Panicked("no message".to_string()))
+        );
+    }
+}
@@ -0,0 +1,64 @@
+/*!
+# 2023 Day 1: Trebuchet?!
+##  Number line
+
+<https://adventofcode.com/2023/day/1>
+
+This is a simple introduction to Rust. I'm doing some functional style
+processing to find the first and last digits. I originally used a line iterator
+(see history), but simplified it to reading the whole file for most examples, as
+Advent of Code input will always fit in memory.  I left the original line
+iterator for Day 1b (also in Day 3, which is a bit more complex), see history.
+
+To keep this simple as possible, I've put 1b in a separate file. Most days
+compute everything in one.
+
+Day 1 used to be the only day that supported Rust nightlies' experimental script
+mode (`cargo script`); see history for that, too.
+*/
+
+use crate::{ParseError, PartTwo, Solution};
+
+fn number_line(line: &str) -> u32 {
+    let mut chars = line.chars().filter_map(|c| c.to_digit(10));
+    let start = chars.next().unwrap();
+    let end = chars.next_back().unwrap_or(start);
+    10 * start + end
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Ok(text.lines().map(ToString::to_string).collect())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.iter().map(|line| number_line(line)).sum()
+    }
+}
+
+impl PartTwo for Day01 {
+    type Answer2 = u32;
+
+    /// Part 2 lives in [`super::day01b`].
+    fn part2(input: &Self::Input) -> u32 {
+        super::day01b::compute2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day01, 1);
+
+    #[test]
+    fn test_number_line() {
+        assert_eq!(number_line("pqr3stu8vwx"), 38);
+        assert_eq!(number_line("treb7uchet"), 77);
+    }
+}
@@ -1,5 +1,3 @@
-#!/usr/bin/env cargo -Zscript
-
 /*!
 # 2023 Day 1: Trebuchet?!
 ## Number line (part 2)
@@ -7,10 +5,10 @@
 <https://adventofcode.com/2023/day/1>
 
 This is part 2. This could probably use `strum`, but I went with simple and performant.
+This used to read the file a line at a time with a `BufReader` (see history);
+now it provides the second part of [`super::day01::Day01`].
 */
 
-use std::io::prelude::*;
-
 const NUMS: &[&str] = &[
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
@@ -44,12 +42,10 @@ const fn pair_to_int(pair: (u32, u32)) -> u32 {
     10 * pair.0 + pair.1
 }
 
-fn main() {
-    // This uses a BufReader to read the file line by line.
-    let file = std::fs::File::open("input/01.txt").unwrap();
-    let lines = std::io::BufReader::new(file).lines();
-    let sum = lines.fold(0, |x, line| x + pair_to_int(str_to_pair(&line.unwrap())));
-    println!("Sum: {sum}");
+pub(crate) fn compute2(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .fold(0, |x, line| x + pair_to_int(str_to_pair(line)))
 }
 
 #[cfg(test)]
@@ -12,31 +12,27 @@ simplify (pretty much fully replace) the impl blocks. I'm also implementing the
 I'm also doing some error handling here (see history for different version, also
 with error handling). It was rather forced on me by the `FromStr` trait, but I
 think it's a good idea to get used to it instead of simply unwrapping all the
-time. Which I'm going to most of the time, since these are controlled inputs.
-
-I'm currently not using `anyhow` and `thiserror`, but might in the future. This
-would make error handling and messages better, and avoid the
-`.or(Err(FailedReadError))`.
+time. This used to have its own empty `FailedReadError` (see history); it now
+uses the crate's [`ParseError`], which points at the offending text.
 */
 
 use derive_more::{Add, Constructor};
 use itertools::Itertools;
 use std::str::FromStr;
 
-/// This is an error for my `FromStr` implementation.
-#[derive(Debug, Clone)]
-struct FailedReadError;
+use crate::parse::{number, split_once, strip_prefix};
+use crate::{ParseError, PartTwo, Solution};
 
 /// This is a measurement of balls, with the number of each color present.
-#[derive(Default, Add, Constructor)]
-struct Meas {
+#[derive(Debug, Default, Add, Constructor)]
+pub struct Meas {
     red: u32,
     green: u32,
     blue: u32,
 }
 
 impl FromStr for Meas {
-    type Err = FailedReadError;
+    type Err = ParseError;
 
     /// This accepts strings of the form `"1 red, 2 green, 3 blue"` and adds
     /// them together.
@@ -45,24 +41,27 @@ impl FromStr for Meas {
             let (val, color) = x
                 .split_ascii_whitespace()
                 .collect_tuple()
-                .ok_or(FailedReadError)?;
-            let val: u32 = val.parse().or(Err(FailedReadError))?;
+                .ok_or_else(|| ParseError::at(x, "expected a count and a color"))?;
+            let val: u32 = number(val)?;
             let current = match color {
                 "red" => Self::new(val, 0, 0),
                 "green" => Self::new(0, val, 0),
                 "blue" => Self::new(0, 0, val),
-                _ => Err(FailedReadError)?,
+                _ => Err(ParseError::at(color, format!("unknown color {color:?}")))?,
             };
             Ok(acc + current)
         })
     }
 }
 
-fn measurements(line: &str) -> (u32, Vec<Meas>) {
-    let (game_str, results) = line.split_once(':').unwrap();
-    let game_number: u32 = game_str.strip_prefix("Game ").unwrap().parse().unwrap();
-    let meas = results.split(';').map(|x| x.parse().unwrap()).collect();
-    (game_number, meas)
+fn measurements(line: &str) -> Result<(u32, Vec<Meas>), ParseError> {
+    let (game_str, results) = split_once(line, ":")?;
+    let game_number: u32 = number(strip_prefix(game_str, "Game ")?)?;
+    let meas = results
+        .split(';')
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    Ok((game_number, meas))
 }
 
 fn valid_measurements(max: &Meas, all_meas: &[Meas]) -> bool {
@@ -71,17 +70,15 @@ fn valid_measurements(max: &Meas, all_meas: &[Meas]) -> bool {
         .all(|x| max.red >= x.red && max.blue >= x.blue && max.green >= x.green)
 }
 
-fn accumulator(acc: u32, line: &str) -> u32 {
-    let (game_number, all_meas) = measurements(line);
-    if valid_measurements(&Meas::new(12, 13, 14), &all_meas) {
+fn accumulator(acc: u32, (game_number, all_meas): &(u32, Vec<Meas>)) -> u32 {
+    if valid_measurements(&Meas::new(12, 13, 14), all_meas) {
         acc + game_number
     } else {
         acc
     }
 }
 
-fn total_power(line: &str) -> u32 {
-    let (_, all_meas) = measurements(line);
+fn total_power(all_meas: &[Meas]) -> u32 {
     let total = all_meas.iter().fold(Meas::default(), |acc, x| {
         Meas::new(
             std::cmp::max(acc.red, x.red),
@@ -92,36 +89,54 @@ fn total_power(line: &str) -> u32 {
     total.red * total.green * total.blue
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/02.txt").unwrap();
-    let sum = text.lines().fold(0, accumulator);
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(u32, Vec<Meas>)>;
+    type Answer1 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.lines().map(measurements).collect()
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.iter().fold(0, accumulator)
+    }
+}
+
+impl PartTwo for Day02 {
+    type Answer2 = u32;
 
-    let pow = text.lines().fold(0, |acc, x| acc + total_power(x));
-    println!("Sum: {sum}");
-    println!("Total power: {pow}");
+    fn part2(input: &Self::Input) -> u32 {
+        input
+            .iter()
+            .map(|(_, all_meas)| total_power(all_meas))
+            .sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    const INPUT: &str = "\
-Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    crate::example_tests!(Day02, 2);
+    const INPUT: &str = include_str!("../../examples/02/example.txt");
 
     #[test]
     fn test_02() {
-        let full_total = INPUT.lines().fold(0, |acc, x| acc + measurements(x).0);
+        let full_total = INPUT
+            .lines()
+            .fold(0, |acc, x| acc + measurements(x).unwrap().0);
         assert_eq!(full_total, 15);
-        let sum = INPUT.lines().fold(0, accumulator);
-        assert_eq!(sum, 8);
     }
 
     #[test]
-    fn test_02b() {
-        let pow = INPUT.lines().fold(0, |acc, x| acc + total_power(x));
-        assert_eq!(pow, 2286);
+    fn test_02_error() {
+        let text = "Game 1: 3 blue\nGame 2: 1 blue, 2 purple";
+        let err = Day02::parse(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 19: unknown color \"purple\""
+        );
     }
 }
@@ -4,27 +4,39 @@
 
 <https://adventofcode.com/2023/day/3>
 
-This creates a grid of numbers and gears, stored as pairs. It uses a buffered
-file reader, which reads the file a line at a time (not required, as the file
-isn't that large, but interesting). The implementation is mostly in the struct's
+This creates a grid of numbers and gears, stored as pairs. It used to use a
+buffered file reader, which reads the file a line at a time (not required, as
+the file isn't that large, but interesting; see history), so it still works on
+an iterator of lines. The implementation is mostly in the struct's
 impl block. The struct is mostly just for passing values around together,
-though.
+though. Adjacency (diagonals count) uses `Position::neighbors8` from
+`grid_helper`.
 */
 
-use std::io::prelude::*;
+use crate::grid_helper::Position;
+use crate::parse::number;
+use crate::{ParseError, PartTwo, Solution};
 
-const fn adjacent(x: usize, y: usize, cx: usize, cy: usize, sz: usize) -> bool {
-    (y == cy || y == cy + 1 || y + 1 == cy) && x <= cx + 1 && cx < x + 1 + sz
+/// A number's row, starting column, width, and value.
+type Number = (usize, usize, usize, u32);
+
+/// Whether a number touches the symbol at (row, column), diagonals included.
+fn adjacent(&(y, x, sz, _): &Number, symbol: (usize, usize)) -> bool {
+    Position::try_from(symbol)
+        .into_iter()
+        .flat_map(Position::neighbors8)
+        .filter_map(|pos| <(usize, usize)>::try_from(pos).ok())
+        .any(|(ny, nx)| ny == y && (x..x + sz).contains(&nx))
 }
 
-struct NumberGrid {
+pub struct NumberGrid {
     chars: Vec<(usize, usize)>,
     gears: Vec<(usize, usize)>,
-    numbers: Vec<(usize, usize, usize, u32)>,
+    numbers: Vec<Number>,
 }
 
 impl NumberGrid {
-    fn from_lines(lines: impl Iterator<Item = String>) -> Self {
+    fn from_lines<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Self, ParseError> {
         let mut numbers = Vec::new();
         let mut chars = Vec::new();
         let mut gears = Vec::new();
@@ -41,45 +53,35 @@ impl NumberGrid {
                     while end < bytes.len() && bytes[end].is_ascii_digit() {
                         end += 1;
                     }
-                    let num_str = std::str::from_utf8(&bytes[x..end]).unwrap();
-                    let num = num_str.parse().unwrap();
+                    let num = number(&line[x..end])?;
                     numbers.push((y, x, end - x, num));
                 }
             }
         }
 
-        Self {
+        Ok(Self {
             chars,
             gears,
             numbers,
-        }
+        })
     }
 
-    fn filtered_numbers(&self) -> Vec<&(usize, usize, usize, u32)> {
+    fn filtered_numbers(&self) -> Vec<&Number> {
         self.numbers
             .iter()
-            .filter(|(y, x, sz, _)| {
-                self.chars
-                    .iter()
-                    .any(|(cy, cx)| adjacent(*x, *y, *cx, *cy, *sz))
-            })
+            .filter(|num| self.chars.iter().any(|&symbol| adjacent(num, symbol)))
             .collect()
     }
 
     fn gear_ratios(&self) -> Vec<u32> {
         self.gears
             .iter()
-            .filter_map(|(cy, cx)| {
+            .filter_map(|&gear| {
                 let numbers: Vec<u32> = self
                     .numbers
                     .iter()
-                    .filter_map(|(y, x, sz, num)| {
-                        if adjacent(*x, *y, *cx, *cy, *sz) {
-                            Some(*num)
-                        } else {
-                            None
-                        }
-                    })
+                    .filter(|num| adjacent(num, gear))
+                    .map(|num| num.3)
                     .collect();
                 if numbers.len() == 2 {
                     Some(numbers[0] * numbers[1])
@@ -91,39 +93,40 @@ impl NumberGrid {
     }
 }
 
-fn main() {
-    let file = std::fs::File::open("input/03.txt").unwrap();
-    let lines = std::io::BufReader::new(file).lines();
-    let grid = NumberGrid::from_lines(lines.map(std::result::Result::unwrap));
-    let nums = grid.filtered_numbers();
-    let sum: u32 = nums.iter().map(|x| x.3).sum();
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = NumberGrid;
+    type Answer1 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        NumberGrid::from_lines(text.lines())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.filtered_numbers().iter().map(|x| x.3).sum()
+    }
+}
 
-    println!("Sum: {sum}");
+impl PartTwo for Day03 {
+    type Answer2 = u32;
 
-    let ratios: u32 = grid.gear_ratios().iter().sum();
-    println!("Ratios: {ratios:?}");
+    fn part2(input: &Self::Input) -> u32 {
+        input.gear_ratios().iter().sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
+    crate::example_tests!(Day03, 3);
+
+    const INPUT: &str = include_str!("../../examples/03/example.txt");
 
     #[test]
     fn test_03() {
-        let lines = INPUT.lines().map(std::string::ToString::to_string);
-        let grid = NumberGrid::from_lines(lines);
+        let grid = NumberGrid::from_lines(INPUT.lines()).unwrap();
         assert_eq!(grid.chars.len(), 6);
         assert_eq!(grid.numbers.len(), 10);
         assert!(grid.numbers.contains(&(0, 0, 3, 467)));
@@ -137,11 +140,6 @@ mod tests {
         let vals: Vec<u32> = nums.iter().map(|x| x.3).collect();
         assert!(!vals.contains(&114));
         assert!(!vals.contains(&58));
-
-        let sum: u32 = nums.iter().map(|x| x.3).sum();
-        assert_eq!(sum, 4361);
-
-        let ratios: u32 = grid.gear_ratios().iter().sum();
-        assert_eq!(ratios, 467_835);
+        assert_eq!(grid.gear_ratios(), [16345, 451_490]);
     }
 }
@@ -0,0 +1,115 @@
+/*!
+# 2023 Day 4: Scratchcards
+## Winning cards
+
+<https://adventofcode.com/2023/day/4>
+
+This is a simple counting problem with cards holding numbers. The solution
+covers most of what 3 did, custom struct, `FromStr`, etc. It used to have its
+own error type too (see history); now it uses the crate's [`ParseError`].
+*/
+
+use std::str::FromStr;
+
+use crate::parse::{numbers, split_once};
+use crate::{ParseError, PartTwo, Solution};
+
+#[derive(Debug)]
+pub struct Card {
+    winning: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let data = split_once(line, ":")?.1;
+        let (winning_str, numbers_str) = split_once(data, "|")?;
+        Ok(Self {
+            winning: numbers(winning_str)?,
+            numbers: numbers(numbers_str)?,
+        })
+    }
+}
+
+impl Card {
+    fn count_winning(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|x| self.winning.contains(x))
+            .count()
+    }
+
+    fn score(&self) -> u32 {
+        let score = u32::try_from(self.count_winning()).unwrap();
+        if score == 0 {
+            0
+        } else {
+            u32::pow(2, score - 1)
+        }
+    }
+}
+
+fn card_count(cards: &[Card]) -> Vec<usize> {
+    let wins: Vec<usize> = cards.iter().map(Card::count_winning).collect();
+    let mut card_count: Vec<usize> = cards.iter().map(|_| 1).collect();
+    for (n, win) in wins.iter().enumerate() {
+        for w in (n + 1)..std::cmp::min(*win + n + 1, card_count.len()) {
+            card_count[w] += card_count[n];
+        }
+    }
+    card_count
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Answer1 = u32;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.lines().map(str::parse).collect()
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        input.iter().map(Card::score).sum()
+    }
+}
+
+impl PartTwo for Day04 {
+    type Answer2 = usize;
+
+    fn part2(input: &Self::Input) -> usize {
+        card_count(input).iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day04, 4);
+
+    const INPUT: &str = include_str!("../../examples/04/example.txt");
+
+    #[test]
+    fn test_04() {
+        let cards = Day04::parse(INPUT).unwrap();
+        assert_eq!(cards.len(), 6);
+        assert_eq!(cards[0].winning.len(), 5);
+        assert_eq!(cards[0].numbers.len(), 8);
+        assert_eq!(cards[0].count_winning(), 4);
+        assert_eq!(cards[0].score(), 8);
+        assert_eq!(card_count(&cards), [1, 2, 4, 8, 14, 1]);
+    }
+
+    #[test]
+    fn test_04_error() {
+        let text = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30";
+        let err = Day04::parse(text).unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((2, 12)));
+        let err = Day04::parse("Card 1: 41 48").unwrap_err();
+        assert_eq!(err.message(), "expected \"|\"");
+    }
+}
@@ -0,0 +1,456 @@
+/*!
+# 2023 Day 5: If You Give A Seed A Fertilizer
+## Mapping ranges
+
+<https://adventofcode.com/2023/day/5>
+
+
+This used to compute part 2 the brute-force way, pushing every seed through
+the maps. It was slow enough to need a progress bar (an `indicatif` feature,
+see history). Now whole ranges go through the maps instead, split wherever
+they cross a map's edge, so there are only ever a handful of them. I used the
+built-in `Range` instead of a custom one for a while (see history); now ranges
+are the crate's own `Interval` and `IntervalSet`. I'm also using the
+`derive_new` crate to add new functions with default values to structs.
+
+The maps also run backwards, from a location to every seed that could reach
+it, and `AllMappers::chain` spells out any seed's trip through the almanac,
+which is a good way to sanity-check an input.
+
+I'm also using `itertools` to get a nice tuple conversion.
+*/
+
+use derive_new::new;
+use itertools::Itertools;
+
+use core::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{expect_next, numbers, split_once};
+use crate::{ParseError, PartTwo, Solution};
+
+#[derive(Debug, new)]
+pub struct Mapper {
+    range: Interval<u64>,
+    to: u64,
+}
+
+impl Mapper {
+    fn convert(&self, value: u64) -> Option<u64> {
+        if self.range.contains(value) {
+            Some(value - self.range.low() + self.to)
+        } else {
+            None
+        }
+    }
+
+    /// The part of `ranges` this maps, and where it maps to.
+    fn convert_ranges(&self, ranges: &IntervalSet<u64>) -> (IntervalSet<u64>, IntervalSet<u64>) {
+        let hit = ranges.intersection(&self.range.into());
+        let mapped = hit
+            .iter()
+            .map(|&range| range - self.range.low() + self.to)
+            .collect();
+        (hit, mapped)
+    }
+
+    /// The values this maps to.
+    fn destination(&self) -> Interval<u64> {
+        self.range - self.range.low() + self.to
+    }
+
+    /// The values in `ranges` this can map to, and where they come from.
+    fn invert_ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        ranges
+            .intersection(&self.destination().into())
+            .iter()
+            .map(|&range| range - self.to + self.range.low())
+            .collect()
+    }
+}
+
+impl FromStr for Mapper {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (to, from, size) = numbers::<u64>(line)?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::at(line, "expected three numbers"))?;
+        if size == 0 {
+            return Err(ParseError::at(line, "expected a range length above zero"));
+        }
+        // Both ends have to fit, so `convert` can't overflow either.
+        let last = size - 1;
+        match (from.checked_add(last), to.checked_add(last)) {
+            (Some(high), Some(_)) => Ok(Self::new(Interval::new(from, high), to)),
+            _ => Err(ParseError::at(line, "expected a range that fits in a u64")),
+        }
+    }
+}
+
+/// One map in the almanac, like `seed-to-soil`.
+#[derive(new)]
+pub struct Mappers {
+    source: String,
+    destination: String,
+    #[new(default)]
+    entries: Vec<Mapper>,
+}
+
+impl Mappers {
+    fn convert(&self, value: u64) -> u64 {
+        for mapper in &self.entries {
+            if let Some(result) = mapper.convert(value) {
+                return result;
+            }
+        }
+        value
+    }
+
+    /// Map every value in `ranges`; values outside all the mappers are kept.
+    fn convert_ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let (unmapped, mapped) = self.entries.iter().fold(
+            (ranges.clone(), IntervalSet::new()),
+            |(unmapped, mapped), mapper| {
+                let (hit, moved) = mapper.convert_ranges(&unmapped);
+                (unmapped.difference(&hit), mapped.union(&moved))
+            },
+        );
+        mapped.union(&unmapped)
+    }
+
+    /// Every value that maps into `ranges`. Like `convert`, the first mapper
+    /// covering a value is the one used, so where mappers overlap the later
+    /// ones only count the values the earlier ones left alone.
+    #[must_use]
+    pub fn invert_ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let (sources, found) = self.entries.iter().fold(
+            (IntervalSet::new(), IntervalSet::new()),
+            |(sources, found), mapper| {
+                let from = mapper.invert_ranges(ranges).difference(&sources);
+                (sources.union(&mapper.range.into()), found.union(&from))
+            },
+        );
+        found.union(&ranges.difference(&sources))
+    }
+}
+
+#[derive(new)]
+pub struct AllMappers {
+    #[new(default)]
+    mappers: Vec<Mappers>,
+}
+
+impl AllMappers {
+    #[must_use]
+    pub fn convert(&self, value: u64) -> u64 {
+        let mut value = value;
+        for mapper in &self.mappers {
+            value = mapper.convert(value);
+        }
+        value
+    }
+
+    #[must_use]
+    pub fn convert_ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.mappers.iter().fold(ranges.clone(), |ranges, mapper| {
+            mapper.convert_ranges(&ranges)
+        })
+    }
+
+    /// Every seed that ends up at `location`.
+    #[must_use]
+    pub fn invert(&self, location: u64) -> IntervalSet<u64> {
+        self.invert_ranges(&Interval::new(location, location).into())
+    }
+
+    /// Every seed that ends up somewhere in `locations`.
+    #[must_use]
+    pub fn invert_ranges(&self, locations: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.mappers
+            .iter()
+            .rev()
+            .fold(locations.clone(), |ranges, mapper| {
+                mapper.invert_ranges(&ranges)
+            })
+    }
+
+    /// Each step from `seed` to its location, for checking an almanac by
+    /// hand. Any seed works, not just the ones the almanac lists.
+    ///
+    /// ```
+    /// use aoc2023::Solution;
+    /// use aoc2023::days::day05::Day05;
+    ///
+    /// let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/05/example.txt");
+    /// let (_, almanac) = Day05::parse(&std::fs::read_to_string(path).unwrap()).unwrap();
+    /// assert_eq!(
+    ///     almanac.chain(14).to_string(),
+    ///     "seed 14, soil 14, fertilizer 53, water 49, light 42, temperature 42, humidity 43, location 43"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn chain(&self, seed: u64) -> Chain<'_> {
+        let first = self.mappers.first().map_or("seed", |m| &m.source);
+        let mut steps = vec![(first, seed)];
+        let mut value = seed;
+        for mapper in &self.mappers {
+            value = mapper.convert(value);
+            steps.push((&mapper.destination, value));
+        }
+        Chain { steps }
+    }
+}
+
+/// A seed's trip through the almanac. Displays like the puzzle does:
+/// `seed 79, soil 81, fertilizer 81, ..., location 82`.
+pub struct Chain<'a> {
+    steps: Vec<(&'a str, u64)>,
+}
+
+impl Display for Chain<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.steps.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name} {value}")?;
+        }
+        Ok(())
+    }
+}
+
+fn read<'a>(lines: impl Iterator<Item = &'a str>) -> Result<(Vec<u64>, AllMappers), ParseError> {
+    let mut lines = lines;
+    let seeds_text = split_once(expect_next(&mut lines, "seeds")?, ":")?.1;
+    let seeds: Vec<u64> = numbers(seeds_text)?;
+    // Part 2 reads the same numbers as (start, length) pairs.
+    if seeds.len() % 2 != 0 || seeds.chunks(2).all(|pair| pair[1] == 0) {
+        return Err(ParseError::at(
+            seeds_text,
+            "expected seeds as (start, length) pairs, with at least one length above zero",
+        ));
+    }
+    if seeds
+        .chunks(2)
+        .any(|pair| pair[1] > 0 && pair[0].checked_add(pair[1] - 1).is_none())
+    {
+        return Err(ParseError::at(
+            seeds_text,
+            "expected seed ranges that fit in a u64",
+        ));
+    }
+    let blank = expect_next(&mut lines, "a blank line")?;
+    if !blank.is_empty() {
+        return Err(ParseError::at(blank, "expected a blank line"));
+    }
+
+    let mut all_mappers = AllMappers::new();
+    for _ in 0..7 {
+        let header = expect_next(&mut lines, "a map header")?;
+        let name = header
+            .strip_suffix(" map:")
+            .ok_or_else(|| ParseError::at(header, "expected a map header"))?;
+        let (source, destination) = split_once(name, "-to-")?;
+        let mut mappers = Mappers::new(source.to_string(), destination.to_string());
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            mappers.entries.push(line.parse()?);
+        }
+        all_mappers.mappers.push(mappers);
+    }
+    Ok((seeds, all_mappers))
+}
+
+/// Read the seeds as (start, length) pairs, skipping any empty ones.
+fn seed_ranges(seeds: &[u64]) -> impl Iterator<Item = Interval<u64>> + '_ {
+    let pairs = seeds.iter().step_by(2).zip(seeds.iter().skip(1).step_by(2));
+    pairs
+        .filter(|(_, len)| **len > 0)
+        .map(|(from, len)| Interval::new(*from, *from + *len - 1))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<u64>, AllMappers);
+    type Answer1 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read(text.lines())
+    }
+
+    fn part1((seeds, all_mappers): &Self::Input) -> u64 {
+        // There's at least one seed, checked when reading.
+        seeds.iter().map(|x| all_mappers.convert(*x)).min().unwrap()
+    }
+}
+
+impl PartTwo for Day05 {
+    type Answer2 = u64;
+
+    fn part2((seeds, all_mappers): &Self::Input) -> u64 {
+        // There's at least one seed range, checked when reading.
+        let locations = all_mappers.convert_ranges(&seed_ranges(seeds).collect());
+        locations.iter().next().unwrap().low()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day05, 5);
+
+    const INPUT: &str = include_str!("../../examples/05/example.txt");
+
+    #[test]
+    fn test_05() {
+        let (seeds, all_mappers) = read(INPUT.lines()).unwrap();
+        assert_eq!(seeds, vec![79, 14, 55, 13]);
+        assert_eq!(all_mappers.mappers.len(), 7);
+        assert_eq!(all_mappers.mappers[0].convert(79), 81);
+        assert_eq!(all_mappers.mappers[0].convert(14), 14);
+        assert_eq!(all_mappers.mappers[0].convert(55), 57);
+        assert_eq!(all_mappers.mappers[0].convert(13), 13);
+
+        assert_eq!(all_mappers.mappers[1].convert(81), 81);
+        assert_eq!(all_mappers.mappers[2].convert(81), 81);
+        assert_eq!(all_mappers.mappers[3].convert(81), 74);
+        assert_eq!(all_mappers.mappers[4].convert(74), 78);
+        assert_eq!(all_mappers.mappers[5].convert(78), 78);
+        assert_eq!(all_mappers.mappers[6].convert(78), 82);
+
+        assert_eq!(all_mappers.convert(79), 82);
+        assert_eq!(all_mappers.convert(14), 43);
+        assert_eq!(all_mappers.convert(55), 86);
+        assert_eq!(all_mappers.convert(13), 35);
+    }
+
+    fn seeds_as_ranges_brute_force(seeds: &[u64]) -> impl Iterator<Item = u64> + '_ {
+        seed_ranges(seeds).flat_map(|range| range.low()..=range.high())
+    }
+
+    #[test]
+    fn test_05b_brute_force() {
+        let (seeds, all_mappers) = read(INPUT.lines()).unwrap();
+        let all_seeds = seeds_as_ranges_brute_force(&seeds);
+        let min = all_seeds.map(|x| all_mappers.convert(x)).min().unwrap();
+        assert_eq!(min, 46);
+    }
+
+    #[test]
+    fn test_05b_ranges() {
+        let (seeds, all_mappers) = read(INPUT.lines()).unwrap();
+        let seed_set: IntervalSet<u64> = seed_ranges(&seeds).collect();
+        let locations = all_mappers.convert_ranges(&seed_set);
+        assert_eq!(locations.size(), seed_set.size());
+
+        // Every seed lands in the ranges, and nothing else does.
+        let brute_force: IntervalSet<u64> = seeds_as_ranges_brute_force(&seeds)
+            .map(|x| Interval::new(all_mappers.convert(x), all_mappers.convert(x)))
+            .collect();
+        assert_eq!(locations, brute_force);
+
+        // A map that splits a range in the middle.
+        let mut mappers = Mappers::new("a".to_string(), "b".to_string());
+        mappers
+            .entries
+            .push(Mapper::new(Interval::new(10, 19), 100));
+        let split = mappers.convert_ranges(&Interval::new(5, 14).into());
+        assert_eq!(
+            split,
+            [(5, 9), (100, 104)]
+                .into_iter()
+                .map(|(a, b)| Interval::new(a, b))
+                .collect()
+        );
+    }
+
+    #[test]
+    fn test_bad_almanac() {
+        let parse = |text: &str| {
+            Day05::parse(text)
+                .map(|_| ())
+                .map_err(|err| err.locate(text).to_string())
+        };
+        let (_, maps) = INPUT.split_once("\n\n").unwrap();
+        for seeds in ["seeds:", "seeds: 79", "seeds: 79 0"] {
+            let err = parse(&format!("{seeds}\n\n{maps}")).unwrap_err();
+            assert!(err.contains("at least one length above zero"), "{err}");
+        }
+        let text = format!("seeds: 18446744073709551615 2\n\n{maps}");
+        assert!(parse(&text).unwrap_err().contains("fit in a u64"));
+        let text = format!("seeds: 18446744073709551615 1\n\n{maps}");
+        assert!(parse(&text).is_ok());
+
+        assert!("0 18446744073709551615 1".parse::<Mapper>().is_ok());
+        for line in ["0 18446744073709551615 2", "18446744073709551615 0 2"] {
+            let err = line.parse::<Mapper>().unwrap_err();
+            assert_eq!(err.to_string(), "expected a range that fits in a u64");
+        }
+    }
+
+    #[test]
+    fn test_chain() {
+        let (_, all_mappers) = read(INPUT.lines()).unwrap();
+        assert_eq!(
+            all_mappers.chain(79).to_string(),
+            "seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82"
+        );
+        // A seed the almanac doesn't list still goes all the way through.
+        let chain = all_mappers.chain(1000).to_string();
+        assert!(chain.starts_with("seed 1000, soil 1000, "), "{chain}");
+        assert!(
+            chain.ends_with(&format!("location {}", all_mappers.convert(1000))),
+            "{chain}"
+        );
+    }
+
+    #[test]
+    fn test_invert() {
+        let (seeds, all_mappers) = read(INPUT.lines()).unwrap();
+        for seed in seeds {
+            assert!(all_mappers.invert(all_mappers.convert(seed)).contains(seed));
+        }
+        assert_eq!(all_mappers.invert(46), Interval::new(82, 82).into());
+
+        // The seed ranges are exactly what maps into their locations.
+        let seed_set: IntervalSet<u64> = seed_ranges(&[79, 14, 55, 13]).collect();
+        let locations = all_mappers.convert_ranges(&seed_set);
+        let back = all_mappers.invert_ranges(&locations);
+        assert_eq!(back.intersection(&seed_set), seed_set);
+        for range in back.iter() {
+            for seed in [range.low(), range.high()] {
+                assert!(locations.contains(all_mappers.convert(seed)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invert_overlapping() {
+        let mut mappers = Mappers::new("a".to_string(), "b".to_string());
+        mappers.entries.push(Mapper::new(Interval::new(0, 9), 100));
+        mappers.entries.push(Mapper::new(Interval::new(5, 14), 200));
+
+        // 7 is covered by the first mapper, so it goes to 107, not 202.
+        let target = Interval::new(202, 202).into();
+        assert_eq!(mappers.invert_ranges(&target), target);
+
+        let target: IntervalSet<u64> = [Interval::new(100, 109), Interval::new(200, 212)]
+            .into_iter()
+            .collect();
+        let back = mappers.invert_ranges(&target);
+        for value in 0..=300 {
+            assert_eq!(
+                back.contains(value),
+                target.contains(mappers.convert(value)),
+                "{value}"
+            );
+        }
+    }
+}
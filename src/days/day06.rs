@@ -0,0 +1,108 @@
+/*!
+# 2023 Day 6: Wait For It
+## Races
+
+<https://adventofcode.com/2023/day/6>
+
+This continues to use a few helpers (as always with the first 10 days or so,
+check history for pure implementations). I'm not implementing `FromStr` since
+races are recorded vertically.
+*/
+
+use derive_more::Constructor;
+use itertools::Itertools;
+
+use crate::parse::{numbers, split_once};
+use crate::{ParseError, PartTwo, Solution};
+
+#[derive(Constructor)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn ways_to_win(&self) -> u64 {
+        (0..(self.time))
+            .map(|a_time| u64::from(a_time * (self.time - a_time) > self.distance))
+            .sum()
+    }
+}
+
+fn get_arr(string: &str) -> Result<Vec<u64>, ParseError> {
+    numbers(split_once(string, ":")?.1)
+}
+
+/// Join the numbers as if the spaces between them were removed.
+fn join(values: &[u64]) -> u64 {
+    values.iter().join("").parse().unwrap()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Vec<u64>, Vec<u64>);
+    type Answer1 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let (time, distance) = text
+            .lines()
+            .map(get_arr)
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new("expected a time and a distance line"))?;
+        let (time, distance) = (time?, distance?);
+        if time.len() != distance.len() {
+            return Err(ParseError::new("every race needs a time and a distance"));
+        }
+        Ok((time, distance))
+    }
+
+    fn part1((time, distance): &Self::Input) -> u64 {
+        let races: Vec<Race> = time
+            .iter()
+            .zip(distance)
+            .map(|(t, d)| Race::new(*t, *d))
+            .collect();
+        races.iter().map(Race::ways_to_win).product()
+    }
+}
+
+impl PartTwo for Day06 {
+    type Answer2 = u64;
+
+    fn part2((time, distance): &Self::Input) -> u64 {
+        Race::new(join(time), join(distance)).ways_to_win()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day06, 6);
+
+    const INPUT: &str = include_str!("../../examples/06/example.txt");
+
+    #[test]
+    fn test_read() {
+        let (time, distance) = Day06::parse(INPUT).unwrap();
+        let races: Vec<Race> = time
+            .iter()
+            .zip(&distance)
+            .map(|(t, d)| Race::new(*t, *d))
+            .collect();
+        let ways_to_win: Vec<u64> = races.iter().map(Race::ways_to_win).collect();
+
+        assert_eq!(time, vec![7, 15, 30]);
+        assert_eq!(distance, vec![9, 40, 200]);
+        assert_eq!(ways_to_win, vec![4, 8, 9]);
+
+        let (time, distance) = INPUT
+            .lines()
+            .map(|x| get_arr(&x.replace(' ', "")).unwrap()[0])
+            .collect_tuple()
+            .unwrap();
+        let ways_to_win = Race::new(time, distance).ways_to_win();
+        assert_eq!(ways_to_win, 71503);
+    }
+}
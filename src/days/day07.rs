@@ -26,8 +26,11 @@ use derive_more::Constructor;
 use itertools::Itertools;
 use strum::EnumString;
 
+use crate::parse::{char_at, number};
+use crate::{ParseError, PartTwo, Solution};
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, EnumString)]
-enum Card {
+pub enum Card {
     #[strum(serialize = "?")]
     Joker,
     #[strum(serialize = "2")]
@@ -58,8 +61,8 @@ enum Card {
     Ace,
 }
 
-#[derive(Debug, PartialEq, Eq, Constructor)]
-struct Hand {
+#[derive(Debug, Clone, PartialEq, Eq, Constructor)]
+pub struct Hand {
     cards: [Card; 5],
     bid: u64,
 }
@@ -72,6 +75,14 @@ fn count(cards: &[Card]) -> HashMap<Card, u64> {
 }
 
 impl Hand {
+    /// The same hand, with every Jack played as a Joker instead.
+    fn with_jokers(&self) -> Self {
+        let cards = self
+            .cards
+            .map(|x| if x == Card::Jack { Card::Joker } else { x });
+        Self::new(cards, self.bid)
+    }
+
     fn level(&self) -> u64 {
         let card_counts = count(&self.cards);
         let mut counts: Vec<_> = card_counts.values().copied().collect();
@@ -125,55 +136,70 @@ impl PartialOrd for Hand {
 }
 
 impl FromStr for Hand {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (hand_str, bid_str) = s.split_ascii_whitespace().collect_tuple().unwrap();
+        let (hand_str, bid_str) = s
+            .split_ascii_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::at(s, "expected a hand and a bid"))?;
 
         let cards: Vec<Card> = hand_str
-            .chars()
-            .map(|x| x.to_string().parse().unwrap())
-            .collect();
-
-        let bid: u64 = bid_str.parse().unwrap();
-        Ok(Self::new(cards.try_into().unwrap(), bid))
+            .char_indices()
+            .map(|(i, _)| {
+                let card = char_at(hand_str, i);
+                card.parse()
+                    .map_err(|_| ParseError::at(card, format!("unknown card {card:?}")))
+            })
+            .collect::<Result<_, _>>()?;
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseError::at(hand_str, "expected five cards"))?;
+
+        let bid: u64 = number(bid_str)?;
+        Ok(Self::new(cards, bid))
     }
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/07.txt").unwrap();
-    let mut hands: Vec<Hand> = text.lines().map(|x| x.parse().unwrap()).collect();
+fn winnings(mut hands: Vec<Hand>) -> u64 {
     hands.sort();
-    let score: u64 = hands
+    hands
         .iter()
         .enumerate()
         .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-        .sum();
-    println!("Total winnings: {score}");
+        .sum()
+}
 
-    let mut hands: Vec<Hand> = text
-        .lines()
-        .map(|x| x.replace('J', "?").parse().unwrap())
-        .collect();
-    hands.sort();
-    let score: u64 = hands
-        .iter()
-        .enumerate()
-        .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-        .sum();
-    println!("Total winnings using Jokers: {score}");
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Answer1 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.lines().map(str::parse).collect()
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        winnings(input.clone())
+    }
+}
+
+impl PartTwo for Day07 {
+    type Answer2 = u64;
+
+    fn part2(input: &Self::Input) -> u64 {
+        winnings(input.iter().map(Hand::with_jokers).collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
+    crate::example_tests!(Day07, 7);
+
+    const INPUT: &str = include_str!("../../examples/07/example.txt");
 
     #[test]
     fn test_parse() {
@@ -191,13 +217,6 @@ QQQJA 483";
         assert_eq!(hands[2].bid, 28);
         assert_eq!(hands[3].bid, 684);
         assert_eq!(hands[4].bid, 483);
-
-        let score: u64 = hands
-            .iter()
-            .enumerate()
-            .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-            .sum();
-        assert_eq!(score, 6440);
     }
 
     #[test]
@@ -218,13 +237,6 @@ QQQJA 483";
         assert_eq!(hands[2].bid, 684);
         assert_eq!(hands[3].bid, 483);
         assert_eq!(hands[4].bid, 220);
-
-        let score: u64 = hands
-            .iter()
-            .enumerate()
-            .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-            .sum();
-        assert_eq!(score, 5905);
     }
 
     #[test]
@@ -24,8 +24,11 @@ use derive_more::Constructor;
 use itertools::Itertools;
 use strum::EnumString;
 
+use crate::parse::{char_at, number};
+use crate::{ParseError, PartTwo, Solution};
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, EnumString)]
-enum StdCard {
+pub enum StdCard {
     #[strum(serialize = "2")]
     Two,
     #[strum(serialize = "3")]
@@ -55,7 +58,7 @@ enum StdCard {
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, EnumString)]
-enum JokerCard {
+pub enum JokerCard {
     #[strum(serialize = "J")]
     Joker,
     #[strum(serialize = "2")]
@@ -84,7 +87,7 @@ enum JokerCard {
     Ace,
 }
 
-trait Card: Hash + Eq + Copy + Debug + Ord + FromStr {
+pub trait Card: Hash + Eq + Copy + Debug + Ord + FromStr {
     fn is_joker(&self) -> bool;
 }
 
@@ -100,8 +103,8 @@ impl Card for JokerCard {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Constructor)]
-struct Hand<T> {
+#[derive(Debug, Clone, PartialEq, Eq, Constructor)]
+pub struct Hand<T> {
     cards: [T; 5],
     bid: u64,
 }
@@ -165,54 +168,75 @@ impl<T: Card> PartialOrd for Hand<T> {
 impl<T> FromStr for Hand<T>
 where
     T: FromStr + Debug,
-    <T as FromStr>::Err: Debug,
 {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (hand_str, bid_str) = s.split_ascii_whitespace().collect_tuple().unwrap();
+        let (hand_str, bid_str) = s
+            .split_ascii_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::at(s, "expected a hand and a bid"))?;
 
         let cards: Vec<T> = hand_str
-            .chars()
-            .map(|x| x.to_string().parse().unwrap())
-            .collect();
-
-        let bid: u64 = bid_str.parse().unwrap();
-        Ok(Self::new(cards.try_into().unwrap(), bid))
+            .char_indices()
+            .map(|(i, _)| {
+                let card = char_at(hand_str, i);
+                card.parse()
+                    .map_err(|_| ParseError::at(card, format!("unknown card {card:?}")))
+            })
+            .collect::<Result<_, _>>()?;
+        let cards = cards
+            .try_into()
+            .map_err(|_| ParseError::at(hand_str, "expected five cards"))?;
+
+        let bid: u64 = number(bid_str)?;
+        Ok(Self::new(cards, bid))
     }
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/07.txt").unwrap();
-    let mut hands: Vec<Hand<StdCard>> = text.lines().map(|x| x.parse().unwrap()).collect();
+fn winnings<T: Card>(mut hands: Vec<Hand<T>>) -> u64 {
     hands.sort();
-    let score: u64 = hands
+    hands
         .iter()
         .enumerate()
         .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-        .sum();
-    println!("Total winnings: {score}");
+        .sum()
+}
 
-    let mut hands: Vec<Hand<JokerCard>> = text.lines().map(|x| x.parse().unwrap()).collect();
-    hands.sort();
-    let score: u64 = hands
-        .iter()
-        .enumerate()
-        .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-        .sum();
-    println!("Total winnings using Jokers: {score}");
+pub struct Day07Trait;
+
+/// Each game reads the cards differently, so both sets of hands are parsed.
+impl Solution for Day07Trait {
+    type Input = (Vec<Hand<StdCard>>, Vec<Hand<JokerCard>>);
+    type Answer1 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Ok((
+            text.lines().map(str::parse).collect::<Result<_, _>>()?,
+            text.lines().map(str::parse).collect::<Result<_, _>>()?,
+        ))
+    }
+
+    fn part1((hands, _): &Self::Input) -> u64 {
+        winnings(hands.clone())
+    }
+}
+
+impl PartTwo for Day07Trait {
+    type Answer2 = u64;
+
+    fn part2((_, hands): &Self::Input) -> u64 {
+        winnings(hands.clone())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
+    crate::example_tests!(Day07Trait, 7);
+
+    const INPUT: &str = include_str!("../../examples/07/example.txt");
 
     #[test]
     fn test_parse() {
@@ -230,13 +254,6 @@ QQQJA 483";
         assert_eq!(hands[2].bid, 28);
         assert_eq!(hands[3].bid, 684);
         assert_eq!(hands[4].bid, 483);
-
-        let score: u64 = hands
-            .iter()
-            .enumerate()
-            .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-            .sum();
-        assert_eq!(score, 6440);
     }
 
     #[test]
@@ -255,13 +272,6 @@ QQQJA 483";
         assert_eq!(hands[2].bid, 684);
         assert_eq!(hands[3].bid, 483);
         assert_eq!(hands[4].bid, 220);
-
-        let score: u64 = hands
-            .iter()
-            .enumerate()
-            .map(|(rank, hand)| (rank as u64 + 1) * hand.bid)
-            .sum();
-        assert_eq!(score, 5905);
     }
 
     #[test]
@@ -0,0 +1,500 @@
+/*!
+# 2023 Day 8: Haunted Wasteland
+## Maze
+
+<https://adventofcode.com/2023/day/8>
+
+This is a maze. For some reason, part two has a weird structure in the data that
+I finally discovered and exploited (each ghost hits one Z, exactly once per
+loop, so the answer was an LCM; see history). Now it does the harder, general
+thing: find each ghost's loop with the `cycle` module, and line up every Z it
+hits with the CRT from `number_theory`. I'm now using regex to parse the input,
+and the nodes are numbered as they're read, so the walks don't look up names.
+Both parts walk from some start nodes to some end nodes, and a walk that can't
+get there stops once it loops.
+*/
+
+use core::fmt::{self, Display, Formatter};
+use itertools::Itertools;
+
+use indexmap::IndexSet;
+use regex::Regex;
+
+use crate::cycle::History;
+use crate::number_theory::{CrtError, crt};
+use crate::parse::{char_at, expect_next};
+use crate::{ParseError, PartTwo, Solution};
+
+/// The nodes, numbered in the order they're listed, with the numbers of their
+/// left and right neighbors.
+#[derive(Debug)]
+pub struct Network {
+    names: IndexSet<String>,
+    links: Vec<[u32; 2]>,
+}
+
+impl Network {
+    fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    fn id(&self, name: &str) -> Option<u32> {
+        self.names.get_index_of(name).map(to_id)
+    }
+
+    fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+
+    /// Where a turn (0 for left, 1 for right) from `node` leads.
+    fn step(&self, node: u32, turn: u8) -> u32 {
+        self.links[node as usize][usize::from(turn)]
+    }
+
+    fn ids_where(&self, pred: impl Fn(&str) -> bool) -> impl Iterator<Item = u32> {
+        self.names
+            .iter()
+            .positions(move |name| pred(name))
+            .map(to_id)
+    }
+
+    /// Whether each node's name matches, so walks don't look at names.
+    fn mask(&self, pred: impl Fn(&str) -> bool) -> Vec<bool> {
+        self.names.iter().map(|name| pred(name)).collect()
+    }
+}
+
+fn to_id(index: usize) -> u32 {
+    u32::try_from(index).expect("fewer than 2^32 nodes")
+}
+
+/// The directions as turns (0 for left, 1 for right) and the network.
+fn read(text: &str) -> Result<(Vec<u8>, Network), ParseError> {
+    let mut lines = text.lines();
+    let directions = expect_next(&mut lines, "directions")?;
+    if let Some(i) = directions.find(|c| c != 'L' && c != 'R') {
+        let c = char_at(directions, i);
+        return Err(ParseError::at(c, format!("invalid direction {c:?}")));
+    }
+    if directions.is_empty() {
+        return Err(ParseError::at(directions, "expected directions"));
+    }
+    let blank = expect_next(&mut lines, "a blank line")?;
+    if !blank.is_empty() {
+        return Err(ParseError::at(blank, "expected a blank line"));
+    }
+    let re = Regex::new(r"^([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)$").unwrap();
+    let entries: Vec<(&str, &str, &str)> = lines
+        .map(|x| {
+            re.captures(x)
+                .and_then(|caps| {
+                    caps.iter()
+                        .skip(1)
+                        .map(|y| Some(y?.as_str()))
+                        .collect::<Option<Vec<_>>>()?
+                        .into_iter()
+                        .collect_tuple()
+                })
+                .ok_or_else(|| ParseError::at(x, "expected a node like `AAA = (BBB, CCC)`"))
+        })
+        .collect::<Result<_, _>>()?;
+    let mut names = IndexSet::new();
+    for (name, _, _) in &entries {
+        if !names.insert((*name).to_string()) {
+            return Err(ParseError::at(name, format!("duplicate node {name:?}")));
+        }
+    }
+    let mut network = Network {
+        names,
+        links: Vec::new(),
+    };
+    let find = |name: &str| {
+        network
+            .id(name)
+            .ok_or_else(|| ParseError::at(name, format!("unknown node {name:?}")))
+    };
+    network.links = entries
+        .iter()
+        .map(|(_, l, r)| Ok([find(l)?, find(r)?]))
+        .collect::<Result<_, ParseError>>()?;
+    let turns = directions.bytes().map(|b| u8::from(b == b'R')).collect();
+    Ok((turns, network))
+}
+
+/// Where one walk is on an end node. It wanders for `lead` steps before
+/// falling into a loop of `period` steps (a loop over both the node and the
+/// place in the directions), so `early` hits happen once and `looping` ones
+/// repeat.
+#[derive(Debug)]
+struct Walk {
+    lead: u64,
+    period: u64,
+    early: Vec<u64>,
+    looping: Vec<u64>,
+}
+
+impl Walk {
+    fn is_at_end(&self, step: u64) -> bool {
+        if step < self.lead {
+            self.early.contains(&step)
+        } else {
+            let offset = (step - self.lead) % self.period;
+            self.looping.contains(&(self.lead + offset))
+        }
+    }
+
+    /// The first step on an end node, if it ever gets to one.
+    fn first_end(&self) -> Option<u64> {
+        self.early.first().or_else(|| self.looping.first()).copied()
+    }
+}
+
+fn walk(directions: &[u8], network: &Network, start: u32, is_end: &[bool]) -> Walk {
+    let history = History::find((start, 0), |&(node, i)| {
+        (
+            network.step(node, directions[i]),
+            (i + 1) % directions.len(),
+        )
+    });
+    let cycle = history.cycle().expect("a finite walk always loops");
+    log::debug!(
+        "From {}: a loop of {} steps after {}",
+        network.name(start),
+        cycle.len,
+        cycle.start
+    );
+    let (early, looping) = history
+        .states()
+        .iter()
+        .positions(|&(node, _)| is_end[node as usize])
+        .map(|step| step as u64)
+        .partition(|&step| step < cycle.start as u64);
+    Walk {
+        lead: cycle.start as u64,
+        period: cycle.len as u64,
+        early,
+        looping,
+    }
+}
+
+/// Why there's no walk from a start node to an end node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathError {
+    NoStart,
+    Unreachable,
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStart => write!(f, "there is no start node"),
+            Self::Unreachable => write!(f, "no end node can be reached from the start"),
+        }
+    }
+}
+
+/// The fewest steps from any start node to any end node. Fails if there are
+/// no start nodes, or if every walk loops without reaching an end.
+fn follow_directions(
+    directions: &[u8],
+    network: &Network,
+    is_start: impl Fn(&str) -> bool,
+    is_end: impl Fn(&str) -> bool,
+) -> Result<u64, PathError> {
+    let is_end = network.mask(is_end);
+    let mut starts = network.ids_where(is_start).peekable();
+    if starts.peek().is_none() {
+        return Err(PathError::NoStart);
+    }
+    starts
+        .filter_map(|start| walk(directions, network, start, &is_end).first_end())
+        .min()
+        .ok_or(PathError::Unreachable)
+}
+
+/// Why the ghosts can't all reach a Z node together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GhostError {
+    NoStarts,
+    Never,
+    Overflow,
+}
+
+impl Display for GhostError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoStarts => write!(f, "there are no start nodes for the ghosts"),
+            Self::Never => write!(f, "the ghosts are never all on an end node at once"),
+            Self::Overflow => write!(f, "the ghosts only line up past u64::MAX steps"),
+        }
+    }
+}
+
+/// The first step where every ghost (starting on each start node) is on an
+/// end node.
+///
+/// Before all the ghosts are looping, each step is just checked. After that,
+/// every ghost is at an end at some offsets modulo its period, so each way of
+/// picking one offset per ghost is a set of congruences for the CRT.
+fn follow_directions_ghosts(
+    directions: &[u8],
+    network: &Network,
+    is_start: impl Fn(&str) -> bool,
+    is_end: impl Fn(&str) -> bool,
+) -> Result<u64, GhostError> {
+    let is_end = network.mask(is_end);
+    let walks: Vec<Walk> = network
+        .ids_where(is_start)
+        .map(|start| walk(directions, network, start, &is_end))
+        .collect();
+    if walks.is_empty() {
+        return Err(GhostError::NoStarts);
+    }
+    let lead = walks.iter().map(|walk| walk.lead).max().unwrap_or(0);
+    if let Some(step) = (0..lead).find(|&step| walks.iter().all(|walk| walk.is_at_end(step))) {
+        return Ok(step);
+    }
+
+    let mut options = vec![(0, 1)];
+    for walk in &walks {
+        options = options
+            .iter()
+            .cartesian_product(&walk.looping)
+            .filter_map(
+                |(&combined, &offset)| match crt([combined, (offset, walk.period)]) {
+                    Ok(solution) => Some(Ok(solution)),
+                    Err(CrtError::NoSolution { .. }) => None,
+                    Err(CrtError::Overflow) => Some(Err(GhostError::Overflow)),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        options.sort_unstable();
+        options.dedup();
+    }
+    // The solutions repeat, so find the first one once everyone is looping.
+    options
+        .into_iter()
+        .map(|(step, period)| {
+            if step >= lead {
+                return Ok(step);
+            }
+            (lead - step)
+                .div_ceil(period)
+                .checked_mul(period)
+                .and_then(|extra| extra.checked_add(step))
+                .ok_or(GhostError::Overflow)
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .min()
+        .ok_or(GhostError::Never)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<u8>, Network);
+    type Answer1 = Result<u64, PathError>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let (directions, network) = read(text)?;
+        log::info!("Number of Directions: {}", directions.len());
+        log::info!("Number of Nodes: {}", network.len());
+        Ok((directions, network))
+    }
+
+    fn part1((directions, network): &Self::Input) -> Result<u64, PathError> {
+        follow_directions(
+            directions,
+            network,
+            |name| name == "AAA",
+            |name| name == "ZZZ",
+        )
+    }
+}
+
+impl PartTwo for Day08 {
+    type Answer2 = Result<u64, GhostError>;
+
+    fn part2((directions, network): &Self::Input) -> Result<u64, GhostError> {
+        follow_directions_ghosts(
+            directions,
+            network,
+            |name| name.ends_with('A'),
+            |name| name.ends_with('Z'),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{Day, SolveError};
+    crate::example_tests!(Day08, 8);
+
+    const INPUT1: &str = include_str!("../../examples/08/example1.txt");
+    const INPUT2: &str = include_str!("../../examples/08/example2.txt");
+
+    const INPUT3: &str = include_str!("../../examples/08/example3.txt");
+
+    fn neighbors<'a>(network: &'a Network, name: &str) -> [&'a str; 2] {
+        network.links[network.id(name).unwrap() as usize].map(|id| network.name(id))
+    }
+
+    fn is_a(name: &str) -> bool {
+        name.ends_with('A')
+    }
+
+    fn is_z(name: &str) -> bool {
+        name.ends_with('Z')
+    }
+
+    #[test]
+    fn test_01() {
+        let (directions, network) = read(INPUT1).unwrap();
+        assert_eq!(directions, [1, 0]);
+        assert_eq!(neighbors(&network, "AAA"), ["BBB", "CCC"]);
+        assert_eq!(neighbors(&network, "BBB"), ["DDD", "EEE"]);
+        assert_eq!(neighbors(&network, "CCC"), ["ZZZ", "GGG"]);
+        assert_eq!(neighbors(&network, "DDD"), ["DDD", "DDD"]);
+        assert_eq!(neighbors(&network, "EEE"), ["EEE", "EEE"]);
+        assert_eq!(neighbors(&network, "GGG"), ["GGG", "GGG"]);
+        assert_eq!(neighbors(&network, "ZZZ"), ["ZZZ", "ZZZ"]);
+    }
+
+    #[test]
+    fn test_02() {
+        let (directions, network) = read(INPUT2).unwrap();
+        assert_eq!(directions, [0, 0, 1]);
+        assert_eq!(neighbors(&network, "AAA"), ["BBB", "BBB"]);
+        assert_eq!(neighbors(&network, "BBB"), ["AAA", "ZZZ"]);
+        assert_eq!(neighbors(&network, "ZZZ"), ["ZZZ", "ZZZ"]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // The walk from AAA loops through AAA and BBB, so it stops instead of
+        // giving up after some number of steps.
+        let text = "LR
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)
+";
+        let (directions, network) = read(text).unwrap();
+        let is_end = network.mask(|x| x == "ZZZ");
+        let walk = walk(&directions, &network, network.id("AAA").unwrap(), &is_end);
+        assert_eq!((walk.lead, walk.period), (0, 2));
+        assert_eq!(walk.first_end(), None);
+        assert_eq!(
+            follow_directions(&directions, &network, |x| x == "AAA", |x| x == "ZZZ"),
+            Err(PathError::Unreachable)
+        );
+        // From either of the other nodes, ZZZ is close.
+        assert_eq!(
+            follow_directions(&directions, &network, |x| x != "AAA", |x| x == "ZZZ"),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_part1_errors() {
+        // The ghost example has no AAA, and is only checked for part 2.
+        let input = Day08::parse(include_str!("../../examples/08/example3.txt")).unwrap();
+        assert_eq!(Day08::part1(&input), Err(PathError::NoStart));
+
+        let text = "L
+
+AAA = (BBB, BBB)
+BBB = (AAA, AAA)
+ZZZ = (ZZZ, ZZZ)
+";
+        let parsed = (Day::new::<Day08>(8, "08").parse)(text).unwrap();
+        assert_eq!(
+            parsed.solve(1),
+            Err(SolveError::Failed(PathError::Unreachable.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_b() {
+        let (directions, network) = read(INPUT3).unwrap();
+        assert_eq!(network.ids_where(is_a).count(), 2);
+        let walk = walk(
+            &directions,
+            &network,
+            network.id("22A").unwrap(),
+            &network.mask(is_z),
+        );
+        assert_eq!((walk.lead, walk.period), (1, 6));
+        assert_eq!(walk.looping, [3, 6]);
+    }
+
+    #[test]
+    fn test_b_general() {
+        // One ghost is on a Z at steps 1 and 2 of a loop of 3, the other at
+        // step 3 of a loop of 4 that starts at step 1, so they meet at 7 (not
+        // at the LCM of the first hits).
+        let text = "L
+
+11A = (11Z, 11Z)
+11Z = (12Z, 12Z)
+12Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22D, 22D)
+22D = (22B, 22B)
+";
+        let (directions, network) = read(text).unwrap();
+        let walk = walk(
+            &directions,
+            &network,
+            network.id("22A").unwrap(),
+            &network.mask(is_z),
+        );
+        assert_eq!((walk.lead, walk.period), (1, 4));
+        assert_eq!(walk.looping, [3]);
+        assert_eq!(
+            follow_directions_ghosts(&directions, &network, is_a, is_z),
+            Ok(7)
+        );
+    }
+
+    #[test]
+    fn test_b_never() {
+        // The ghosts are on a Z at odd and even steps.
+        let text = "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)
+";
+        let (directions, network) = read(text).unwrap();
+        assert_eq!(
+            follow_directions_ghosts(&directions, &network, is_a, is_z),
+            Err(GhostError::Never)
+        );
+        // The runner reports it rather than panicking.
+        let parsed = (Day::new::<Day08>(8, "08").parse)(text).unwrap();
+        assert_eq!(
+            parsed.solve(2),
+            Err(SolveError::Failed(GhostError::Never.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_b_no_starts() {
+        let text = "L
+
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+";
+        let input = Day08::parse(text).unwrap();
+        assert_eq!(Day08::part2(&input), Err(GhostError::NoStarts));
+    }
+}
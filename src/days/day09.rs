@@ -7,12 +7,20 @@
 This one is very simple and didn't need anything special, just simple recursion.
 */
 
-fn read(input: &str) -> impl Iterator<Item = Vec<i64>> + '_ {
-    input.lines().map(|line| {
-        line.split_whitespace()
-            .map(|x| x.parse().unwrap())
-            .collect()
-    })
+use crate::parse::numbers;
+use crate::{ParseError, PartTwo, Solution};
+
+fn read(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let values = numbers(line)?;
+            if values.is_empty() {
+                return Err(ParseError::at(line, "expected a sequence of numbers"));
+            }
+            Ok(values)
+        })
+        .collect()
 }
 
 fn compute_next(data: &[i64]) -> i64 {
@@ -35,28 +43,40 @@ fn compute_previous(data: &[i64]) -> i64 {
     *data.first().unwrap() - previous
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/09.txt").unwrap();
-    let data: Vec<_> = read(&text).collect();
-    let forward_sum: i64 = data.iter().map(|x| compute_next(x)).sum();
-    println!("Total forward: {forward_sum}");
-    let backward_sum: i64 = data.iter().map(|x| compute_previous(x)).sum();
-    println!("Total backward: {backward_sum}");
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read(text)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        input.iter().map(|x| compute_next(x)).sum()
+    }
+}
+
+impl PartTwo for Day09 {
+    type Answer2 = i64;
+
+    fn part2(input: &Self::Input) -> i64 {
+        input.iter().map(|x| compute_previous(x)).sum()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45
-";
+    crate::example_tests!(Day09, 9);
+
+    const INPUT: &str = include_str!("../../examples/09/example.txt");
 
     #[test]
     fn test() {
-        let data: Vec<_> = read(INPUT).collect();
+        let data = read(INPUT).unwrap();
         assert_eq!(data.len(), 3);
         assert_eq!(data[0], vec![0, 3, 6, 9, 12, 15]);
         assert_eq!(compute_next(&data[0]), 18);
@@ -0,0 +1,327 @@
+/*!
+# 2023 Day 10: Pipe Maze
+## Maps
+
+<https://adventofcode.com/2023/day/10>
+
+This was originally done by hand, but was much better using `strum`, `Grid`,
+etc.  From the beginning, though, I wanted the pretty color map output. It was
+really easy once I started using these crates.
+
+This used to stand-alone, but now uses `Direction` from the `grid_helper` module.
+The `S` is swapped for the pipe under it while parsing, so nothing else has to
+treat it specially.
+Part 2 used to cast a ray from every cell to see if it was inside; now the loop
+is a `polygon`, and Pick's theorem counts the cells inside from its area. The
+ray casting is still used to draw the inside when debug logging.
+*/
+
+use derive_more::Constructor;
+use grid::Grid;
+use strum::IntoEnumIterator;
+
+use crate::grid_helper::{Color, Direction, Renderer, find_marker, parse_grid};
+use crate::parse::char_at;
+use crate::polygon::Polygon;
+use crate::{ParseError, PartTwo, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display)]
+pub enum MapChar {
+    #[strum(serialize = "S")]
+    Start,
+
+    #[strum(serialize = "|", to_string = "│")]
+    Vertical,
+
+    #[strum(serialize = "-", to_string = "─")]
+    Horizontal,
+
+    #[strum(serialize = "J", to_string = "╯")]
+    UpLeft,
+
+    #[strum(serialize = "L", to_string = "╰")]
+    UpRight,
+
+    #[strum(serialize = "7", to_string = "╮")]
+    DownLeft,
+
+    #[strum(serialize = "F", to_string = "╭")]
+    DownRight,
+
+    #[strum(serialize = ".", to_string = "•")]
+    Empty,
+}
+
+impl TryFrom<char> for MapChar {
+    type Error = strum::ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.encode_utf8(&mut [0; 4]).parse()
+    }
+}
+
+#[derive(Debug, Clone, Constructor)]
+struct Cursor {
+    y: usize,
+    x: usize,
+}
+
+impl Cursor {
+    #[must_use]
+    const fn step(&self, dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Self::new(self.y - 1, self.x),
+            Direction::Down => Self::new(self.y + 1, self.x),
+            Direction::Left => Self::new(self.y, self.x - 1),
+            Direction::Right => Self::new(self.y, self.x + 1),
+        }
+    }
+
+    #[must_use]
+    fn peek<'a, T>(&self, grid: &'a Grid<T>, dir: Direction) -> Option<&'a T> {
+        match dir {
+            Direction::Up => grid.get(self.y.checked_sub(1)?, self.x),
+            Direction::Down => grid.get(self.y + 1, self.x),
+            Direction::Left => grid.get(self.y, self.x.checked_sub(1)?),
+            Direction::Right => grid.get(self.y, self.x + 1),
+        }
+    }
+
+    /// The pipe under the start, from the neighbors that lead into it.
+    #[must_use]
+    fn start_pipe(&self, grid: &Grid<MapChar>) -> Option<MapChar> {
+        use Direction::{Down, Left, Right, Up};
+
+        match self.connections(grid)? {
+            (Up, Down) | (Down, Up) => Some(MapChar::Vertical),
+            (Left, Right) | (Right, Left) => Some(MapChar::Horizontal),
+            (Up, Left) | (Left, Up) => Some(MapChar::UpLeft),
+            (Up, Right) | (Right, Up) => Some(MapChar::UpRight),
+            (Down, Left) | (Left, Down) => Some(MapChar::DownLeft),
+            (Down, Right) | (Right, Down) => Some(MapChar::DownRight),
+            _ => None,
+        }
+    }
+
+    /// The first two directions whose neighbors lead back here.
+    #[must_use]
+    fn connections(&self, grid: &Grid<MapChar>) -> Option<(Direction, Direction)> {
+        use Direction::{Down, Left, Right, Up};
+        use MapChar::{DownLeft, DownRight, Horizontal, UpLeft, UpRight, Vertical};
+
+        let mut valid_dirs = Direction::iter().filter(|dir| {
+            log::debug!("{self:?} {dir:?}");
+            self.peek(grid, *dir).is_some_and(|next| {
+                log::debug!("Checking ({next}, {dir:?})");
+                matches!(
+                    (next, dir),
+                    (Vertical, Up | Down)
+                        | (Horizontal, Left | Right)
+                        | (UpLeft, Right | Down)
+                        | (UpRight, Left | Down)
+                        | (DownLeft, Right | Up)
+                        | (DownRight, Left | Up)
+                )
+            })
+        });
+        Some((valid_dirs.next()?, valid_dirs.next()?))
+    }
+
+    /// Follow the pipes back to here, marking them in `mask`, and return
+    /// each step taken. If the pipes lead somewhere else, this is the last
+    /// cell reached on the way.
+    fn find_end(
+        &self,
+        start_direction: Direction,
+        grid: &Grid<MapChar>,
+        mask: &mut Grid<bool>,
+    ) -> Result<Vec<Direction>, Self> {
+        use Direction::{Down, Left, Right, Up};
+        use MapChar::{DownLeft, DownRight, Horizontal, UpLeft, UpRight, Vertical};
+
+        let mut cursor = self.clone();
+        let mut current_dir = start_direction;
+        let mut steps = Vec::new();
+        // Each cell is entered at most once, so a walk that never gets back
+        // is stopped when it crosses itself.
+        while !mask[(cursor.y, cursor.x)] {
+            mask[(cursor.y, cursor.x)] = true;
+            steps.push(current_dir);
+            let Some(next) = cursor.peek(grid, current_dir) else {
+                return Err(cursor);
+            };
+            log::debug!("{}: {current_dir:?} -> {next}", steps.len());
+            let previous = cursor.clone();
+            cursor = cursor.step(current_dir);
+            if (cursor.y, cursor.x) == (self.y, self.x) {
+                return Ok(steps);
+            }
+            current_dir = match (next, current_dir) {
+                (Vertical, Up) | (UpLeft, Right) | (UpRight, Left) => Up,
+                (Vertical, Down) | (DownLeft, Right) | (DownRight, Left) => Down,
+                (Horizontal, Left) | (UpLeft, Down) | (DownLeft, Up) => Left,
+                (Horizontal, Right) | (UpRight, Down) | (DownRight, Up) => Right,
+                _ => return Err(previous),
+            };
+        }
+        Err(cursor)
+    }
+}
+
+/// Whether a cell is inside the loop: a ray to the right of it crosses the
+/// loop an odd number of times. Only the pipes with an upward end count, so
+/// running along a pipe like `L-7` is one crossing, and `L-J` is none.
+#[must_use]
+fn is_inside(grid: &Grid<MapChar>, mask: &Grid<bool>, loc: &(usize, usize)) -> bool {
+    use MapChar::{UpLeft, UpRight, Vertical};
+    let (y, min_x) = *loc;
+    let crossings = (min_x..grid.cols())
+        .filter(|&x| mask[(y, x)] && matches!(grid[(y, x)], Vertical | UpLeft | UpRight))
+        .count();
+    crossings % 2 == 1
+}
+
+/// The pipes, with the `S` swapped for the pipe under it, and the loop
+/// through it: the steps around it and a mask of the pipes on it.
+#[derive(Debug, Clone)]
+pub struct Maze {
+    grid: Grid<MapChar>,
+    steps: Vec<Direction>,
+    mask: Grid<bool>,
+}
+
+/// Swap the start for its pipe, and walk the loop, pointing at where it
+/// breaks if it doesn't get back.
+fn read_maze(text: &str) -> Result<Maze, ParseError> {
+    let mut grid = parse_grid(text)?;
+    let marker = find_marker(text, 'S')?;
+    let start = Cursor::new(marker.pos.0, marker.pos.1);
+    let (pipe, (current_dir, _)) = start
+        .start_pipe(&grid)
+        .zip(start.connections(&grid))
+        .ok_or_else(|| marker.error("the start must join two pipes"))?;
+    grid[marker.pos] = pipe;
+
+    let mut mask: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    let steps = start
+        .find_end(current_dir, &grid, &mut mask)
+        .map_err(|Cursor { y, x }| {
+            // The grid only holds ASCII, so columns are byte offsets.
+            let line = text.lines().nth(y).unwrap();
+            ParseError::at(
+                char_at(line, x),
+                "the loop from the start breaks after this pipe",
+            )
+        })?;
+    Ok(Maze { grid, steps, mask })
+}
+/// The cells inside the loop, found by casting a ray from each one.
+fn inside_mask(grid: &Grid<MapChar>, mask: &Grid<bool>) -> Grid<bool> {
+    let mut inside: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    for ((y, x), is_loop) in mask.indexed_iter() {
+        if !is_loop {
+            inside[(y, x)] = is_inside(grid, mask, &(y, x));
+        }
+    }
+    inside
+}
+
+/// Log the map at debug level, with the loop in yellow and the inside in green.
+fn log_grid(grid: &Grid<MapChar>, mask: &Grid<bool>) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+    let inside = inside_mask(grid, mask);
+    let picture = Renderer::new(grid)
+        .highlight(mask, Color::Yellow)
+        .highlight(&inside, Color::Green)
+        .color_for(&std::io::stderr());
+    log::debug!("\n{picture}");
+}
+
+/// The number of cells inside the loop.
+fn count_inside(maze: &Maze) -> usize {
+    log_grid(&maze.grid, &maze.mask);
+
+    let polygon = Polygon::from_steps(maze.steps.iter().map(|&dir| (dir, 1)));
+    usize::try_from(polygon.interior_points()).unwrap()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_maze(text)
+    }
+
+    /// The far side of the loop is halfway around it.
+    fn part1(input: &Self::Input) -> usize {
+        input.steps.len() / 2
+    }
+}
+
+impl PartTwo for Day10 {
+    type Answer2 = usize;
+
+    fn part2(input: &Self::Input) -> usize {
+        count_inside(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day10, 10);
+    use test_log::test;
+
+    #[test]
+    fn test_pick_matches_rays() {
+        for case in crate::examples::load(10).unwrap() {
+            let maze = Day10::parse(&case.text).unwrap();
+            let rays = inside_mask(&maze.grid, &maze.mask)
+                .iter()
+                .filter(|x| **x)
+                .count();
+            assert_eq!(Day10::part2(&maze), rays, "{}", case.name);
+        }
+    }
+
+    #[test]
+    fn test_start_pipe() {
+        let maze = Day10::parse(&crate::examples::load(10).unwrap()[0].text).unwrap();
+        assert_eq!(maze.grid[(1, 1)], MapChar::DownRight);
+        assert!(maze.grid.iter().all(|&c| c != MapChar::Start));
+
+        let text = ".....\n.S-7.\n.|.|.\n.L-J.\n..S..\n";
+        let err = Day10::parse(text).unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((5, 3)));
+        let text = ".....\n.S...\n.....\n";
+        let err = Day10::parse(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: the start must join two pipes"
+        );
+    }
+
+    #[test]
+    fn test_broken_loop() {
+        let text = ".....\n.S-7.\n.|.|.\n.L-..\n.....\n";
+        let err = Day10::parse(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 3: the loop from the start breaks after this pipe"
+        );
+        // Heading down the side, into a pipe that turns away.
+        let text = "S-7\n|.|\nL-|\n";
+        let err = Day10::parse(text).unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((3, 2)));
+        // Off the edge of the map.
+        let text = "S-\nL-\n";
+        let err = Day10::parse(text).unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((2, 2)));
+    }
+}
@@ -8,22 +8,34 @@ The key trick here is storing the galaxies as (x,y) pairs. That makes part 2 muc
 history for the original part 1). Otherwise, it's quite simpler to solve functionally.
 */
 
-fn compute(text: &str, mul: u64) -> u64 {
-    assert!(mul > 0);
-    let orig_galaxies: Vec<(u64, u64)> = text
+use crate::parse::char_at;
+use crate::{ParseError, PartTwo, Solution};
+
+fn read(text: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    let galaxies: Vec<(u64, u64)> = text
         .lines()
         .enumerate()
         .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(move |(x, c)| {
-                if c == '#' {
-                    Some((x as u64, y as u64))
-                } else {
-                    None
-                }
-            })
+            line.char_indices()
+                .enumerate()
+                .filter_map(move |(x, (i, c))| match c {
+                    '#' => Some(Ok((x as u64, y as u64))),
+                    '.' => None,
+                    _ => {
+                        let c = char_at(line, i);
+                        Some(Err(ParseError::at(c, format!("unknown tile {c:?}"))))
+                    }
+                })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
+    if galaxies.is_empty() {
+        return Err(ParseError::new("expected at least one galaxy"));
+    }
+    Ok(galaxies)
+}
 
+fn compute(orig_galaxies: &[(u64, u64)], mul: u64) -> u64 {
+    assert!(mul > 0);
     let width = *orig_galaxies.iter().map(|(x, _)| x).max().unwrap();
     let height = *orig_galaxies.iter().map(|(_, y)| y).max().unwrap();
 
@@ -58,34 +70,41 @@ fn compute(text: &str, mul: u64) -> u64 {
     total
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/11.txt").unwrap();
-    let result = compute(&text, 2);
-    println!("Spacing 1 = {result}");
-    let result = compute(&text, 1_000_000);
-    println!("Spacing 1,000,000 = {result}");
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<(u64, u64)>;
+    type Answer1 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read(text)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        compute(input, 2)
+    }
+}
+
+impl PartTwo for Day11 {
+    type Answer2 = u64;
+
+    fn part2(input: &Self::Input) -> u64 {
+        compute(input, 1_000_000)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
+    crate::example_tests!(Day11, 11);
+
+    const INPUT: &str = include_str!("../../examples/11/example.txt");
 
     #[test]
     fn test() {
-        assert_eq!(compute(INPUT, 2), 374);
-        assert_eq!(compute(INPUT, 10), 1030);
-        assert_eq!(compute(INPUT, 100), 8410);
+        let galaxies = Day11::parse(INPUT).unwrap();
+        assert_eq!(compute(&galaxies, 10), 1030);
+        assert_eq!(compute(&galaxies, 100), 8410);
     }
 }
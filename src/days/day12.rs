@@ -0,0 +1,246 @@
+/*!
+# 2023 Day 12: Hot Springs
+## Missing information
+
+<https://adventofcode.com/2023/day/12>
+
+The key trick here is caching. It takes ~10.5 hours to solve without caching,
+even with rayon multithreading (see history for that). I used to use the
+`cached` library, which provides a Python `itertools.cache`-like syntax for
+Rust, but it copied the rest of the row and groups into a global cache on each
+call. Now the same counts are filled in bottom up, a row of springs for each
+group, over the input bytes, and the folded-up row is never built. The cached
+version is kept in the tests to check against. This takes a few milliseconds.
+*/
+
+use crate::parse::{char_at, number, split_once};
+use crate::{ParseError, PartTwo, Solution};
+
+/// Count the ways to fit `groups` of broken springs into `conditions`, both
+/// unfolded `n` times (with a `?` between the copies of the springs).
+///
+/// Going back from the end of the row, `next[i]` counts the ways to fit the
+/// groups after this one into the springs from `i`, and `row[i]` is the same
+/// for this group onwards. `table` holds the two rows; passing the same one in
+/// for each line means it only grows for longer lines.
+fn arrangements(conditions: &[u8], groups: &[usize], n: usize, table: &mut Vec<usize>) -> usize {
+    let period = conditions.len() + 1;
+    let len = (period * n).saturating_sub(1);
+    let spring = |i: usize| conditions.get(i % period).copied().unwrap_or(b'?');
+
+    table.clear();
+    table.resize(2 * (len + 2), 0);
+    let (mut row, mut next) = table.split_at_mut(len + 2);
+    // With no groups left, there must be no broken springs left. Past the end
+    // (`len + 1`, after a group that ends the row) counts as the end.
+    row[len] = 1;
+    row[len + 1] = 1;
+    for i in (0..len).rev() {
+        row[i] = if spring(i) == b'#' { 0 } else { row[i + 1] };
+    }
+
+    for index in (0..groups.len() * n).rev() {
+        (row, next) = (next, row);
+        let group = groups[index % groups.len()];
+        row[len] = 0;
+        row[len + 1] = 0;
+        // How many springs from `i` on could all be broken.
+        let mut run = 0;
+        for i in (0..len).rev() {
+            let c = spring(i);
+            run = if c == b'.' { 0 } else { run + 1 };
+            let skip = if c == b'#' { 0 } else { row[i + 1] };
+            let end = i + group;
+            let fits = run >= group && (end == len || spring(end) != b'#');
+            row[i] = skip + if fits { next[end + 1] } else { 0 };
+        }
+    }
+    row[0]
+}
+
+fn read_line(text: &str) -> Result<(String, Vec<usize>), ParseError> {
+    let (conditions, ops) = split_once(text, " ")?;
+    if let Some(i) = conditions.find(|c| !matches!(c, '.' | '#' | '?')) {
+        let c = char_at(conditions, i);
+        return Err(ParseError::at(c, format!("unknown spring {c:?}")));
+    }
+    let ops: Vec<usize> = ops.split(',').map(number).collect::<Result<_, _>>()?;
+    Ok((conditions.to_string(), ops))
+}
+
+fn compute(rows: &[(String, Vec<usize>)], n: usize) -> usize {
+    let mut table = Vec::new();
+    rows.iter()
+        .map(|(conditions, ops)| arrangements(conditions.as_bytes(), ops, n, &mut table))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(String, Vec<usize>)>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.lines().map(read_line).collect()
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        compute(input, 1)
+    }
+}
+
+impl PartTwo for Day12 {
+    type Answer2 = usize;
+
+    fn part2(input: &Self::Input) -> usize {
+        compute(input, 5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day12, 12);
+
+    use cached::proc_macro::cached;
+    use itertools::Itertools;
+
+    const INPUT: &str = include_str!("../../examples/12/example.txt");
+
+    /// The old top-down version, memoized with `cached`.
+    #[cached]
+    fn cmp_line(conditions: String, ops: Vec<usize>) -> usize {
+        if ops.is_empty() {
+            return usize::from(!conditions.contains('#'));
+        }
+
+        // The input is ASCII, so byte indexing matches char indexing.
+        let bytes = conditions.as_bytes();
+
+        // The most leading space we can leave before the first group.
+        let limit_space: usize = conditions.len() - (ops.iter().sum::<usize>() + ops.len() - 1);
+        let max_space = conditions
+            .find('#')
+            .unwrap_or(conditions.len())
+            .min(limit_space);
+
+        let mut count = 0;
+        for space in 0..=max_space {
+            let end = space + ops[0];
+            let valid = bytes[space..end].iter().all(|&c| c != b'.')
+                && bytes.get(end).copied().unwrap_or(b'.') != b'#';
+            if conditions.len() - end < 2 {
+                count += usize::from(valid);
+            } else if valid {
+                count += cmp_line(conditions[end + 1..].to_string(), ops[1..].to_vec());
+            }
+        }
+        count
+    }
+
+    fn cached_line((conditions, ops): &(String, Vec<usize>), n: usize) -> usize {
+        cmp_line(vec![conditions.as_str(); n].join("?"), ops.repeat(n))
+    }
+
+    fn single_line((conditions, ops): &(String, Vec<usize>), n: usize) -> usize {
+        arrangements(conditions.as_bytes(), ops, n, &mut Vec::new())
+    }
+
+    #[test]
+    fn test_individual() {
+        assert_eq!(single_line(&read_line("? 1").unwrap(), 1), 1);
+    }
+
+    #[test]
+    fn on_each() {
+        let mut lines = INPUT.lines();
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 1),
+            1
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 1),
+            4
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 1),
+            1
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 1),
+            1
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 1),
+            4
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 1),
+            10
+        );
+    }
+
+    #[test]
+    fn test_5() {
+        let mut lines = INPUT.lines();
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 5),
+            1
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 5),
+            16384
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 5),
+            1
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 5),
+            16
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 5),
+            2500
+        );
+        assert_eq!(
+            single_line(&read_line(lines.next().unwrap()).unwrap(), 5),
+            506_250
+        );
+    }
+
+    #[test]
+    fn test_matches_cached() {
+        // Every row of up to 6 springs, with groups that fit (the cached
+        // version needs that), unfolded a few times.
+        let groups = [vec![1], vec![2], vec![1, 1], vec![3, 1], vec![1, 2, 1]];
+        let mut table = Vec::new();
+        for len in 1..=6 {
+            for springs in itertools::repeat_n(".#?".chars(), len).multi_cartesian_product() {
+                let conditions: String = springs.into_iter().collect();
+                for ops in &groups {
+                    if ops.iter().sum::<usize>() + ops.len() - 1 > len {
+                        continue;
+                    }
+                    let row = (conditions.clone(), ops.clone());
+                    for n in 1..=3 {
+                        assert_eq!(
+                            arrangements(conditions.as_bytes(), ops, n, &mut table),
+                            cached_line(&row, n),
+                            "{conditions} {ops:?} x{n}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(arrangements(b"???", &[], 1, &mut Vec::new()), 1);
+        assert_eq!(arrangements(b"?#?", &[], 2, &mut Vec::new()), 0);
+        assert_eq!(arrangements(b"#", &[1], 0, &mut Vec::new()), 1);
+    }
+}
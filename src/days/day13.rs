@@ -10,6 +10,9 @@ this is fast enough and simpler.
 
 use grid::Grid;
 
+use crate::grid_helper::parse_grid_with;
+use crate::{ParseError, PartTwo, Solution};
+
 /// A mirror line, tagged by axis so row and column results can't be confused.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Mirror {
@@ -35,12 +38,12 @@ impl Mirror {
 }
 
 /// Make a block of bools from a string.
-fn make_block(block: &str) -> Grid<bool> {
-    block
-        .lines()
-        .map(|x| x.chars().map(|x| x == '#').collect())
-        .collect::<Vec<_>>()
-        .into()
+fn make_block(block: &str) -> Result<Grid<bool>, ParseError> {
+    parse_grid_with(block, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
 }
 
 /// Compare around a y mirror line. If you need to compare around x, transpose
@@ -88,64 +91,67 @@ fn compute_block_one_smudge(block: &Grid<bool>) -> Option<Mirror> {
 }
 
 /// Compute all the first part.
-fn compute(text: &str) -> usize {
-    text.split("\n\n")
-        .map(|s| compute_block(&make_block(s), None).expect(s).score())
+fn compute(blocks: &[Grid<bool>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            compute_block(b, None)
+                .unwrap_or_else(|| panic!("No mirror in block {i}"))
+                .score()
+        })
         .sum()
 }
 
 /// Compute all the second part.
-fn compute_one_smudge(text: &str) -> usize {
-    text.split("\n\n")
-        .map(|s| compute_block_one_smudge(&make_block(s)).expect(s).score())
+fn compute_one_smudge(blocks: &[Grid<bool>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            compute_block_one_smudge(b)
+                .unwrap_or_else(|| panic!("No smudged mirror in block {i}"))
+                .score()
+        })
         .sum()
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/13.txt").unwrap();
-    let first_result = compute(&text);
-    let second_result = compute_one_smudge(&text);
-    println!("First = {first_result}");
-    println!("Second = {second_result}");
+/// The blocks are parsed once, and both parts look for mirrors in them.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<bool>>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        text.split("\n\n").map(make_block).collect()
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        compute(input)
+    }
+}
+
+impl PartTwo for Day13 {
+    type Answer2 = usize;
+
+    fn part2(input: &Self::Input) -> usize {
+        compute_one_smudge(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
+    crate::example_tests!(Day13, 13);
 
-    #[test]
-    fn simple() {
-        let first_result = compute(INPUT);
-        assert_eq!(first_result, 405);
-    }
-
-    #[test]
-    fn smudged() {
-        let second_result = compute_one_smudge(INPUT);
-        assert_eq!(second_result, 400);
-    }
+    const INPUT: &str = include_str!("../../examples/13/example.txt");
 
     #[test]
     fn on_each_simple() {
         let blocks = INPUT.split("\n\n");
-        let mut blocks = blocks.map(make_block);
+        let mut blocks = blocks.map(|block| make_block(block).unwrap());
         assert_eq!(
             compute_block(&blocks.next().unwrap(), None)
                 .unwrap()
@@ -164,7 +170,7 @@ mod tests {
     #[test]
     fn on_each_smudged() {
         let blocks = INPUT.split("\n\n");
-        let mut blocks = blocks.map(make_block);
+        let mut blocks = blocks.map(|block| make_block(block).unwrap());
         assert_eq!(
             compute_block_one_smudge(&blocks.next().unwrap())
                 .unwrap()
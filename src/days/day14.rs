@@ -0,0 +1,159 @@
+/*!
+# 2023 Day 14: Parabolic Reflector Dish
+## Tilting rocks
+
+<https://adventofcode.com/2023/day/14>
+
+Implementing this in a single function with a direction required working with
+the grid library quite a bit. I think something like `iter_rows_mut` would have
+been helpful, but it worked out pretty nicely.
+
+Continuing to enjoy enums with `strum`'s additions. Part 2 finds the spin cycle
+loop with the `cycle` module.
+*/
+
+use grid::Grid;
+use strum::IntoEnumIterator;
+
+use crate::cycle::History;
+use crate::grid_helper::parse_grid;
+use crate::{ParseError, PartTwo, Solution};
+
+type Num = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display)]
+pub enum Map {
+    #[strum(serialize = ".")]
+    Empty,
+
+    #[strum(serialize = "O")]
+    RoundRock,
+
+    #[strum(serialize = "#")]
+    CubeRock,
+}
+
+impl TryFrom<char> for Map {
+    type Error = strum::ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.encode_utf8(&mut [0; 4]).parse()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumIter)]
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+fn read_data(text: &str) -> Result<Grid<Map>, ParseError> {
+    parse_grid(text)
+}
+
+fn compute_load(grid: &Grid<Map>) -> Num {
+    grid.indexed_iter()
+        .filter_map(|((y, _), c)| {
+            if *c == Map::RoundRock {
+                Some(grid.rows() - y)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn tilt_cycle(grid: &mut Grid<Map>) {
+    for dir in Direction::iter() {
+        tilt_dir(grid, dir);
+    }
+}
+
+fn tilt_dir(grid: &mut Grid<Map>, dir: Direction) {
+    use Direction::{East, North, South, West};
+    let (outer_range, inner_range) = match dir {
+        North | South => (0..grid.rows(), 1..grid.cols()),
+        West | East => (0..grid.cols(), 1..grid.rows()),
+    };
+
+    for outer in outer_range {
+        let line: &mut Vec<&mut Map> = &mut match dir {
+            West => grid.iter_row_mut(outer).collect(),
+            East => grid.iter_row_mut(outer).rev().collect(),
+            North => grid.iter_col_mut(outer).collect(),
+            South => grid.iter_col_mut(outer).rev().collect(),
+        };
+
+        for y in inner_range.clone() {
+            for i in (1..=y).rev() {
+                if *line[i - 1] == Map::Empty && *line[i] == Map::RoundRock {
+                    *line[i - 1] = Map::RoundRock;
+                    *line[i] = Map::Empty;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+fn compute_cycles(grid: &Grid<Map>, cycles: usize) -> Num {
+    // The platform soon settles into a loop, so the last state is looked up.
+    let history = History::find(grid.clone(), |grid| {
+        let mut grid = grid.clone();
+        tilt_cycle(&mut grid);
+        grid
+    });
+    compute_load(history.state_at(cycles).unwrap())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<Map>;
+    type Answer1 = Num;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_data(text)
+    }
+
+    fn part1(input: &Self::Input) -> Num {
+        let mut grid = input.clone();
+        tilt_dir(&mut grid, Direction::North);
+        compute_load(&grid)
+    }
+}
+
+impl PartTwo for Day14 {
+    type Answer2 = Num;
+
+    fn part2(input: &Self::Input) -> Num {
+        compute_cycles(input, 1_000_000_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day14, 14);
+
+    const INPUT: &str = include_str!("../../examples/14/example.txt");
+
+    #[test]
+    fn test_tilt() {
+        let grid = read_data(INPUT).unwrap();
+        let rocks = |grid: &Grid<Map>| grid.iter().filter(|c| **c == Map::RoundRock).count();
+        let mut tilted = grid.clone();
+        tilt_dir(&mut tilted, Direction::North);
+        assert_eq!(rocks(&tilted), rocks(&grid));
+        // Tilting again doesn't move anything.
+        let before = tilted.clone();
+        tilt_dir(&mut tilted, Direction::North);
+        assert_eq!(tilted, before);
+        // No cycles at all leaves the load as it was.
+        assert_eq!(compute_cycles(&grid, 0), compute_load(&grid));
+    }
+}
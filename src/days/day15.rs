@@ -43,13 +43,15 @@ here since the addition may go over 256.
 
 use indexmap::IndexMap;
 
-fn compute_hash(text: &str) -> usize {
-    let arr = str_to_array(text);
-    arr.into_iter().map(hash).sum()
+use crate::parse::number;
+use crate::{ParseError, PartTwo, Solution};
+
+fn compute_hash(input: &[String]) -> usize {
+    input.iter().map(|x| hash(x)).sum()
 }
 
-fn compute_hashmap(text: &str) -> usize {
-    let arr = hashtable(&str_to_array(text));
+fn compute_hashmap(input: &[String]) -> usize {
+    let arr = hashtable(input);
     arr.into_iter()
         .enumerate()
         .map(|(i, x)| {
@@ -61,9 +63,21 @@ fn compute_hashmap(text: &str) -> usize {
         .sum()
 }
 
-#[inline]
-fn str_to_array(text: &str) -> Vec<&str> {
-    text.trim().split(',').collect()
+fn str_to_array(text: &str) -> Result<Vec<String>, ParseError> {
+    text.trim()
+        .split(',')
+        .map(|step| {
+            if let Some((_, val)) = step.split_once('=') {
+                number::<usize>(val)?;
+            } else if !step.ends_with('-') {
+                return Err(ParseError::at(
+                    step,
+                    format!("expected `=` or `-` in {step:?}"),
+                ));
+            }
+            Ok(step.to_string())
+        })
+        .collect()
 }
 
 #[inline]
@@ -72,11 +86,12 @@ fn hash(text: &str) -> usize {
         .fold(0, |acc, x| ((acc + usize::from(x)) * 17) % 256)
 }
 
-fn hashtable<'a>(input: &[&'a str]) -> Vec<IndexMap<&'a str, usize>> {
+fn hashtable(input: &[String]) -> Vec<IndexMap<&str, usize>> {
     let mut arr: Vec<_> = (0..256).map(|_| IndexMap::<&str, usize>::new()).collect();
 
     for x in input {
         if let Some((code, val)) = x.split_once('=') {
+            // Checked when reading
             let val: usize = val.parse().unwrap();
             arr[hash(code)].insert(code, val);
         } else if let Some((code, _)) = x.split_once('-') {
@@ -86,30 +101,39 @@ fn hashtable<'a>(input: &[&'a str]) -> Vec<IndexMap<&'a str, usize>> {
     arr
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/15.txt").unwrap();
-    let result = compute_hash(&text);
-    println!("HASH = {result}");
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        str_to_array(text)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        compute_hash(input)
+    }
+}
+
+impl PartTwo for Day15 {
+    type Answer2 = usize;
 
-    let result = compute_hashmap(&text);
-    println!("HASHMAP = {result}");
+    fn part2(input: &Self::Input) -> usize {
+        compute_hashmap(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    crate::example_tests!(Day15, 15);
 
     #[test]
     fn test_hash() {
-        let result = compute_hash(INPUT);
-        assert_eq!(result, 1320);
-    }
-
-    #[test]
-    fn test_hashmap() {
-        let result = compute_hashmap(INPUT);
-        assert_eq!(result, 145);
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
     }
 }
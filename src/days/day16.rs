@@ -7,18 +7,19 @@
 The first version was a bit messy. That version added a trait to the grid that
 adds `checked_get` which returns an `Option<&T>`` given a `Position``, with
 isize coords. This was added to the `grid`` crate directly with .get, so this
-workaround was removed.
+workaround was removed; now `GridExt` in `grid_helper` covers it.
 
 It also makes `Direction` support being used like a bitflag, so that the `HashMap`
 in the original version is avoided.
 
-This version was standalone, but was converted to use the (local) `aoc2023` crate.
+This version was standalone, but was converted to use the `grid_helper` module.
 */
 
 use grid::Grid;
 use strum::EnumString;
 
-use aoc2023::grid_helper::{Direction, Position};
+use crate::grid_helper::{Direction, GridExt, Position, parse_grid};
+use crate::{ParseError, PartTwo, Solution};
 
 #[derive(Debug)]
 enum Next {
@@ -27,7 +28,7 @@ enum Next {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, EnumString)]
-enum Tiles {
+pub enum Tiles {
     #[strum(serialize = ".")]
     Empty,
 
@@ -44,6 +45,14 @@ enum Tiles {
     BackwardDiagonal,
 }
 
+impl TryFrom<char> for Tiles {
+    type Error = strum::ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.encode_utf8(&mut [0; 4]).parse()
+    }
+}
+
 impl Tiles {
     const fn next(self, dir: Direction) -> Next {
         use Direction::{Down, Left, Right, Up};
@@ -66,15 +75,8 @@ impl Tiles {
     }
 }
 
-fn parse(text: &str) -> Grid<Tiles> {
-    text.lines()
-        .map(|line| {
-            line.chars()
-                .map(|x| x.to_string().parse::<Tiles>().unwrap())
-                .collect()
-        })
-        .collect::<Vec<_>>()
-        .into()
+fn read_grid(text: &str) -> Result<Grid<Tiles>, ParseError> {
+    parse_grid(text)
 }
 
 fn path(grid: &Grid<Tiles>, pos: &Position, dir: Direction, energized: &mut Grid<u8>) {
@@ -91,7 +93,7 @@ fn path(grid: &Grid<Tiles>, pos: &Position, dir: Direction, energized: &mut Grid
         match tile.next(dir) {
             Single(d) => {
                 let newpos = pos + d;
-                if grid.get(newpos.row(), newpos.col()).is_some() {
+                if grid.contains(newpos) {
                     dir = d;
                     pos = newpos;
                 } else {
@@ -101,10 +103,10 @@ fn path(grid: &Grid<Tiles>, pos: &Position, dir: Direction, energized: &mut Grid
             Double((d1, d2)) => {
                 let pos1 = pos + d1;
                 let pos2 = pos + d2;
-                if grid.get(pos2.row(), pos2.col()).is_some() {
+                if grid.contains(pos2) {
                     path(grid, &pos2, d2, energized);
                 }
-                if grid.get(pos1.row(), pos1.col()).is_some() {
+                if grid.contains(pos1) {
                     dir = d1;
                     pos = pos1;
                 } else {
@@ -128,72 +130,51 @@ fn count_energize(grid: &Grid<Tiles>, pos: &Position, dir: Direction) -> usize {
     total
 }
 
-fn compute1(text: &str) -> usize {
-    let grid = parse(text);
-    let pos = Position::new(0, 0);
-    let dir = Direction::Right;
-    count_energize(&grid, &pos, dir)
-}
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<Tiles>;
+    type Answer1 = usize;
 
-fn compute2(text: &str) -> usize {
-    let grid = parse(text);
-    let mut max = 0;
-    for i in 0..(isize::try_from(grid.rows()).unwrap()) {
-        max = max.max(count_energize(
-            &grid,
-            &Position::new(i, 0),
-            Direction::Right,
-        ));
-        max = max.max(count_energize(
-            &grid,
-            &Position::new(i, isize::try_from(grid.cols()).unwrap() - 1),
-            Direction::Left,
-        ));
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_grid(text)
     }
-    for i in 0..(isize::try_from(grid.cols()).unwrap()) {
-        max = max.max(count_energize(&grid, &Position::new(0, i), Direction::Down));
-        max = max.max(count_energize(
-            &grid,
-            &Position::new(isize::try_from(grid.rows()).unwrap() - 1, i),
-            Direction::Up,
-        ));
+
+    fn part1(grid: &Self::Input) -> usize {
+        let pos = Position::new(0, 0);
+        let dir = Direction::Right;
+        count_energize(grid, &pos, dir)
     }
-    max
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/16.txt").unwrap();
-    let result = compute1(&text);
-    println!("First = {result}");
-
-    let result = compute2(&text);
-    println!("Second = {result}");
+impl PartTwo for Day16 {
+    type Answer2 = usize;
+
+    fn part2(grid: &Self::Input) -> usize {
+        let mut max = 0;
+        for i in 0..(isize::try_from(grid.rows()).unwrap()) {
+            max = max.max(count_energize(grid, &Position::new(i, 0), Direction::Right));
+            max = max.max(count_energize(
+                grid,
+                &Position::new(i, isize::try_from(grid.cols()).unwrap() - 1),
+                Direction::Left,
+            ));
+        }
+        for i in 0..(isize::try_from(grid.cols()).unwrap()) {
+            max = max.max(count_energize(grid, &Position::new(0, i), Direction::Down));
+            max = max.max(count_energize(
+                grid,
+                &Position::new(isize::try_from(grid.rows()).unwrap() - 1, i),
+                Direction::Up,
+            ));
+        }
+        max
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = r".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|....";
-
-    #[test]
-    fn test_first() {
-        let result = compute1(INPUT);
-        assert_eq!(result, 46);
-    }
-
-    #[test]
-    fn test_second() {
-        let result = compute2(INPUT);
-        assert_eq!(result, 51);
-    }
+    crate::example_tests!(Day16, 16);
 }
@@ -0,0 +1,179 @@
+/*!
+# 2023 Day 17: Clumsy Crucible
+## Minimizing the path
+
+<https://adventofcode.com/2023/day/17>
+
+
+Credit to <https://www.youtube.com/watch?v=2pDSooPLLkI> for helping point me
+toward Dijkstra's algorithm. And
+<https://doc.rust-lang.org/std/collections/binary_heap/index.html> for a great
+example of the algorithm to base it on. The search itself now lives in the
+`search` module (as A*, with the distance left as the heuristic); this day
+just describes the moves, and can log the path it found.
+*/
+
+use core::fmt::{self, Display, Formatter};
+use grid::Grid;
+
+use crate::grid_helper::{Color, Direction, GridExt, Position, Renderer, parse_grid_with};
+use crate::search::{Path, astar};
+use crate::{ParseError, PartTwo, Solution};
+
+/// Where the crucible is, which way it's heading, and how long it has been
+/// going straight.
+type State = (Position, Direction, usize);
+
+/// Every block loses at least 1 heat, so a `0` is rejected; the search relies
+/// on that.
+fn read_grid(text: &str) -> Result<Grid<usize>, ParseError> {
+    parse_grid_with(text, |c| {
+        c.to_digit(10).filter(|&x| x > 0).map(|x| x as usize)
+    })
+}
+
+/// The crucible can't get from the top left to the bottom right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPath;
+
+impl Display for NoPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "the crucible can't reach the bottom right")
+    }
+}
+
+/// The moves from a state: keep going one more block (if allowed), or turn
+/// and go at least `min_path` blocks, so every state is a legal place to stop.
+fn moves(
+    grid: &Grid<usize>,
+    (position, direction, len): State,
+    min_path: usize,
+    max_path: usize,
+) -> impl Iterator<Item = (State, usize)> {
+    let directions = [
+        direction,
+        direction.clockwise(),
+        direction.counter_clockwise(),
+    ];
+    directions.into_iter().filter_map(move |dir| {
+        let (steps, new_len) = if dir == direction && len != 0 {
+            (1, len + 1)
+        } else {
+            (min_path, min_path)
+        };
+        if new_len > max_path {
+            return None;
+        }
+        let mut next = position;
+        let mut cost = 0;
+        for _ in 0..steps {
+            next = next + dir;
+            cost += grid.get_pos(next)?;
+        }
+        Some(((next, dir, new_len), cost))
+    })
+}
+
+fn compute_path(
+    grid: &Grid<usize>,
+    min_path: usize,
+    max_path: usize,
+) -> Option<Path<State, usize>> {
+    let goal = Position::new(
+        isize::try_from(grid.rows()).unwrap() - 1,
+        isize::try_from(grid.cols()).unwrap() - 1,
+    );
+    // Every block costs at least 1 (checked when reading), so the distance
+    // left is a lower bound.
+    let distance =
+        |&(pos, _, _): &State| (goal.row() - pos.row() + goal.col() - pos.col()).unsigned_abs();
+    let path = astar(
+        (Position::new(0, 0), Direction::Right, 0),
+        |&state| moves(grid, state, min_path, max_path),
+        distance,
+        |&(pos, _, _)| pos == goal,
+    )?;
+    log_path(grid, &path);
+    Some(path)
+}
+
+/// The blocks the path goes through, including the ones it passes over
+/// between states.
+fn path_mask(grid: &Grid<usize>, path: &Path<State, usize>) -> Grid<bool> {
+    let mut mask = Grid::new(grid.rows(), grid.cols());
+    mask[Position::new(0, 0)] = true;
+    for pair in path.states.windows(2) {
+        let ((mut pos, _, _), (end, dir, _)) = (pair[0], pair[1]);
+        while pos != end {
+            pos = pos + dir;
+            mask[pos] = true;
+        }
+    }
+    mask
+}
+
+/// Log the grid at debug level, with the path taken in yellow.
+fn log_path(grid: &Grid<usize>, path: &Path<State, usize>) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+    let mask = path_mask(grid, path);
+    let picture = Renderer::new(grid)
+        .highlight(&mask, Color::Yellow)
+        .color_for(&std::io::stderr());
+    log::debug!("Heat loss {}:\n{picture}", path.cost);
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+    type Answer1 = Result<usize, NoPath>;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_grid(text)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize, NoPath> {
+        Ok(compute_path(grid, 1, 3).ok_or(NoPath)?.cost)
+    }
+}
+
+impl PartTwo for Day17 {
+    type Answer2 = Result<usize, NoPath>;
+
+    fn part2(grid: &Self::Input) -> Result<usize, NoPath> {
+        Ok(compute_path(grid, 4, 10).ok_or(NoPath)?.cost)
+    }
+}
+
+// Too low: 1147
+// Too high: 1190
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day17, 17);
+
+    #[test]
+    fn test_path_mask() {
+        let grid = read_grid("19\n11\n").unwrap();
+        let path = compute_path(&grid, 1, 3).unwrap();
+        assert_eq!(path.cost, 2);
+        let mask = path_mask(&grid, &path);
+        assert_eq!(mask, Grid::from_vec(vec![true, false, true, true], 2));
+    }
+
+    #[test]
+    fn test_bad_grids() {
+        let text = "11\n10\n";
+        let err = read_grid(text).unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((2, 2)));
+
+        // Part 2 has to go four blocks before it can stop.
+        let grid = read_grid("111\n111\n").unwrap();
+        assert_eq!(Day17::part1(&grid), Ok(3));
+        assert_eq!(Day17::part2(&grid), Err(NoPath));
+    }
+}
@@ -0,0 +1,156 @@
+/*!
+# 2023 Day 18: Lavaduct Lagoon
+## Digging a border
+
+<https://adventofcode.com/2023/day/18>
+
+This is best solved by considering the area added each move.  It was easier for
+part 1 and necessary for part 2.
+
+Moving up or down adds a block of area; as long as you get the sign right, you
+can get the total enclosed area.  You also need the parimeter, which is 1/2
+already acounted for by the area. Corner pieces are 3/4 uncounted, so we need an
+extra 1/4 for each of the four corners required to make the loop. (Any inner
+corners are canceled out by the extra outer corners required.)
+
+The original solution matched the Python version (see history). A cleaner
+solution using functional programming and regex came next; the area math now
+lives in the `polygon` module, which counts the lattice points with the
+shoelace formula and Pick's theorem, so this day just parses the steps.
+The Python solution from my phone (Pythonista):
+
+```python
+directions = [(x.split()[0], int(x.split()[1])) for x in txt.splitlines()]
+
+def get_area(directions):
+    area = 0
+    p = 0
+    loc = [0, 0]
+    for d, l in directions:
+        p += l
+        match d:
+            case "R":
+                loc[1] += l
+            case "L":
+                loc[1] -= l
+            case "D":
+                area += loc[1]*l
+                loc[0] += l
+            case "U":
+                area -= loc[1]*l
+                loc[0] -= l
+
+    return area + p//2 + 1
+
+directions2 = [("RDLU"[int(x.split()[2][-2])], int(x.split()[2][2:-2], 16)) for x in txt.splitlines()]
+
+print(get_area(directions))
+print(get_area(directions2))
+```
+
+*/
+
+use regex::Regex;
+
+use crate::grid_helper::Direction;
+use crate::parse::number;
+use crate::polygon::Polygon;
+use crate::{ParseError, PartTwo, Solution};
+
+type Step = (Direction, i64);
+
+/// The trench dug by `dirs`, which has to end where it started.
+fn read_trench(text: &str, dirs: Vec<Step>) -> Result<Polygon, ParseError> {
+    Polygon::try_from_steps(dirs).ok_or_else(|| {
+        let last = text.lines().last().unwrap_or(text);
+        ParseError::at(last, "the dig plan must end where it starts")
+    })
+}
+fn direction(c: char) -> Direction {
+    match c {
+        'R' => Direction::Right,
+        'L' => Direction::Left,
+        'D' => Direction::Down,
+        'U' => Direction::Up,
+        _ => panic!("Got {c}, expected R, L, D, or U"),
+    }
+}
+
+fn read_directions(text: &str) -> Result<Vec<Step>, ParseError> {
+    let regex = Regex::new(r"^([RLDU]) ([[:digit:]]+) ").unwrap();
+    text.lines()
+        .map(|line| {
+            let cap = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::at(line, "expected a direction and a length"))?;
+            let (_, [digit, length]) = cap.extract();
+            Ok((direction(digit.chars().next().unwrap()), number(length)?))
+        })
+        .collect()
+}
+
+fn read_directions_2(text: &str) -> Result<Vec<Step>, ParseError> {
+    let regex = Regex::new(r"\(\#([[:xdigit:]]{5})([0-3])\)$").unwrap();
+    text.lines()
+        .map(|line| {
+            let cap = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::at(line, "expected a color like `(#70c710)`"))?;
+            let (_, [hexstr, d]) = cap.extract();
+            let d_int = usize::from_str_radix(d, 16).unwrap();
+            let dir = [
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+                Direction::Up,
+            ][d_int];
+            let hex = i64::from_str_radix(hexstr, 16).unwrap();
+            Ok((dir, hex))
+        })
+        .collect()
+}
+
+pub struct Day18;
+
+/// The two parts read different columns, so both trenches are parsed. The
+/// answer is the trench and everything inside it, in cubic meters.
+impl Solution for Day18 {
+    type Input = (Polygon, Polygon);
+    type Answer1 = i64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Ok((
+            read_trench(text, read_directions(text)?)?,
+            read_trench(text, read_directions_2(text)?)?,
+        ))
+    }
+
+    fn part1((trench, _): &Self::Input) -> i64 {
+        trench.lattice_points()
+    }
+}
+
+impl PartTwo for Day18 {
+    type Answer2 = i64;
+
+    fn part2((_, trench): &Self::Input) -> i64 {
+        trench.lattice_points()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day18, 18);
+
+    #[test]
+    fn test_open_plan() {
+        let text = "R 2 (#000020)\nD 2 (#000021)\n";
+        let err = Day18::parse(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: the dig plan must end where it starts"
+        );
+    }
+}
@@ -0,0 +1,473 @@
+/*!
+# 2023 Day 19: Aplenty
+## Command queues
+
+<https://adventofcode.com/2023/day/19>
+
+This uses structs and enums to represent the rules and workflows. I was using
+`intervalium` (provides `interval`) and `gcollections` to properly represent
+intervals (could have been done on day 5 as well); now the crate has its own
+`interval` module, shared with days 5 and 22.
+
+This was originally implemented with a regex (see history), but now uses an
+actual parser.
+*/
+
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::{ops::Index, str::FromStr};
+use strum::EnumString;
+
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{char_at, number, split_once};
+use crate::{ParseError, PartTwo, Solution};
+
+mod my_parser {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+    eoi         = _{ !ANY }
+    cat         = { "x" | "m" | "a" | "s" }
+    compare     = { ("<" | ">") ~ ASCII_DIGIT+ }
+    ident       = { ASCII_ALPHA_LOWER+ }
+    target      = { ident | "A" | "R" }
+    single_rule = { cat ~ compare ~ ":" ~ target }
+    rules       = { (single_rule ~ ",")+ }
+    line        = { ident ~ "{" ~ rules ~ target ~ "}" }
+    file        = { SOI ~ (line ~ NEWLINE*)* ~ eoi }
+    "#]
+    pub struct MyParser;
+}
+
+#[derive(Debug, EnumString, PartialEq, Eq, Hash, Copy, Clone)]
+#[strum(serialize_all = "lowercase")]
+enum Cat {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug)]
+pub struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+impl Part {
+    const fn sum(&self) -> u64 {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+impl Index<Cat> for Part {
+    type Output = u64;
+
+    fn index(&self, cat: Cat) -> &Self::Output {
+        match cat {
+            Cat::X => &self.x,
+            Cat::M => &self.m,
+            Cat::A => &self.a,
+            Cat::S => &self.s,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PartRange {
+    x: IntervalSet<u64>,
+    m: IntervalSet<u64>,
+    a: IntervalSet<u64>,
+    s: IntervalSet<u64>,
+}
+
+impl PartRange {
+    fn sum(&self) -> u64 {
+        self.x.size() * self.m.size() * self.a.size() * self.s.size()
+    }
+
+    fn with_cat(&self, cat: Cat, interval: IntervalSet<u64>) -> Self {
+        match cat {
+            Cat::X => Self {
+                x: interval,
+                ..self.clone()
+            },
+            Cat::M => Self {
+                m: interval,
+                ..self.clone()
+            },
+            Cat::A => Self {
+                a: interval,
+                ..self.clone()
+            },
+            Cat::S => Self {
+                s: interval,
+                ..self.clone()
+            },
+        }
+    }
+
+    fn split(&self, cat: Cat, comp: Compare) -> (Self, Self) {
+        let range = &self[cat];
+        // A rule like `<0` or `>4000` matches nothing, so its range is empty.
+        let rule_range: IntervalSet<u64> = match comp {
+            Compare::LessThan(n) => n.checked_sub(1).and_then(|high| Interval::try_new(0, high)),
+            Compare::GreaterThan(n) => n
+                .checked_add(1)
+                .and_then(|low| Interval::try_new(low, 4000)),
+        }
+        .into_iter()
+        .collect();
+        let intersection = range.intersection(&rule_range);
+        let rule_ranges = self.with_cat(cat, intersection);
+
+        let remaining = range.difference(&rule_range);
+
+        let parts = self.with_cat(cat, remaining);
+        (rule_ranges, parts)
+    }
+}
+
+impl Index<Cat> for PartRange {
+    type Output = IntervalSet<u64>;
+
+    fn index(&self, cat: Cat) -> &Self::Output {
+        match cat {
+            Cat::X => &self.x,
+            Cat::M => &self.m,
+            Cat::A => &self.a,
+            Cat::S => &self.s,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Destination {
+    Workflow(String),
+    Accept,
+    Reject,
+}
+
+impl FromStr for Destination {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Self::Accept),
+            "R" => Ok(Self::Reject),
+            _ => Ok(Self::Workflow(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Compare {
+    LessThan(u64),
+    GreaterThan(u64),
+}
+
+impl Compare {
+    const fn compare(&self, num: u64) -> bool {
+        match self {
+            Self::LessThan(n) => num < *n,
+            Self::GreaterThan(n) => num > *n,
+        }
+    }
+}
+
+impl FromStr for Compare {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (op, num) = s.split_at(1);
+        let num = number(num)?;
+        match op {
+            "<" => Ok(Self::LessThan(num)),
+            ">" => Ok(Self::GreaterThan(num)),
+            _ => Err(ParseError::at(op, format!("unknown comparison {op:?}"))),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    cat: Cat,
+    compare: Compare,
+    dest: Destination,
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    rules: Vec<Rule>,
+    dest: Destination,
+}
+
+impl Workflow {
+    fn next(&self, part: &Part) -> &Destination {
+        for rule in &self.rules {
+            if rule.compare.compare(part[rule.cat]) {
+                return &rule.dest;
+            }
+        }
+        &self.dest
+    }
+}
+
+/// The grammar guarantees the structure, so only the values can fail here.
+/// After this, `in` and every target exist, and no workflow leads back to
+/// itself, so the solving can index the workflows directly and always ends.
+fn read_workflows(txt: &str) -> Result<HashMap<String, Workflow>, ParseError> {
+    use pest::Parser;
+
+    let file =
+        my_parser::MyParser::parse(my_parser::Rule::file, txt)
+            .map_err(|err| {
+                let (pest::error::InputLocation::Pos(pos)
+                | pest::error::InputLocation::Span((pos, _))) = err.location;
+                ParseError::at(char_at(txt, pos), err.variant.message())
+            })?
+            .next()
+            .unwrap();
+
+    // The targets of each workflow, as they appear in the text, so errors
+    // can point at them.
+    let mut targets: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut order = Vec::new();
+    let mut workflows = HashMap::new();
+    for line in file.into_inner() {
+        let mut inner = line.into_inner();
+        let ident = inner.next().unwrap().as_str();
+        let mut to = Vec::new();
+        let single_rule = inner.next().unwrap();
+        let rules: Vec<Rule> = single_rule
+            .into_inner()
+            .map(|rule| {
+                let (cat, compare, dest) = rule.into_inner().next_tuple().unwrap();
+                let cat = cat.as_str().parse().unwrap();
+                let compare = compare.as_str().parse()?;
+                to.push(dest.as_str());
+                let dest = dest.as_str().parse().unwrap();
+                Ok(Rule { cat, compare, dest })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        let dest = inner.next().unwrap().as_str();
+        to.push(dest);
+        let dest: Destination = dest.parse().unwrap();
+        if workflows
+            .insert(ident.to_string(), Workflow { rules, dest })
+            .is_some()
+        {
+            return Err(ParseError::at(
+                ident,
+                format!("duplicate workflow {ident:?}"),
+            ));
+        }
+        targets.insert(ident, to);
+        order.push(ident);
+    }
+
+    if !workflows.contains_key("in") {
+        return Err(ParseError::new("expected an `in` workflow"));
+    }
+    for &target in order.iter().flat_map(|name| &targets[name]) {
+        if !matches!(target, "A" | "R") && !workflows.contains_key(target) {
+            return Err(ParseError::at(
+                target,
+                format!("unknown workflow {target:?}"),
+            ));
+        }
+    }
+    if let Some(target) = find_cycle(&targets, &order) {
+        return Err(ParseError::at(
+            target,
+            format!("workflow {target:?} leads back to itself"),
+        ));
+    }
+    Ok(workflows)
+}
+
+/// A target that leads back to a workflow on the way to it, if the workflows
+/// loop anywhere. They are searched in `order`, so the same one is found each
+/// time.
+fn find_cycle<'a>(targets: &HashMap<&'a str, Vec<&'a str>>, order: &[&'a str]) -> Option<&'a str> {
+    fn visit<'a>(
+        name: &'a str,
+        targets: &HashMap<&'a str, Vec<&'a str>>,
+        on_path: &mut HashSet<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<&'a str> {
+        if done.contains(name) {
+            return None;
+        }
+        on_path.insert(name);
+        // `A` and `R` aren't workflows, so they have no targets.
+        for &target in targets.get(name).into_iter().flatten() {
+            if on_path.contains(target) {
+                return Some(target);
+            }
+            if let Some(found) = visit(target, targets, on_path, done) {
+                return Some(found);
+            }
+        }
+        on_path.remove(name);
+        done.insert(name);
+        None
+    }
+
+    let mut on_path = HashSet::new();
+    let mut done = HashSet::new();
+    order
+        .iter()
+        .find_map(|name| visit(name, targets, &mut on_path, &mut done))
+}
+
+fn read_parts(txt: &str) -> Result<Vec<Part>, ParseError> {
+    let part_regex =
+        Regex::new(r"^\{x=([[:digit:]]+),m=([[:digit:]]+),a=([[:digit:]]+),s=([[:digit:]]+)\}$")
+            .unwrap();
+    txt.lines()
+        .map(|line| {
+            let cap = part_regex
+                .captures(line)
+                .ok_or_else(|| ParseError::at(line, "expected a part like `{x=1,m=2,a=3,s=4}`"))?;
+            let (_, [x, m, a, s]) = cap.extract();
+            Ok(Part {
+                x: number(x)?,
+                m: number(m)?,
+                a: number(a)?,
+                s: number(s)?,
+            })
+        })
+        .collect()
+}
+
+fn read_both(txt: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let (wf, parts) = split_once(txt, "\n\n")?;
+    let wf = read_workflows(wf)?;
+    let parts = read_parts(parts)?;
+    if parts.is_empty() {
+        return Err(ParseError::new("expected at least one part"));
+    }
+    Ok((wf, parts))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+    type Answer1 = u64;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_both(text)
+    }
+
+    fn part1((workflows, parts): &Self::Input) -> u64 {
+        parts
+            .iter()
+            .map(|p| {
+                let mut wf = &workflows["in"];
+                loop {
+                    let dest = wf.next(p);
+                    match dest {
+                        Destination::Accept => return p.sum(),
+                        Destination::Reject => return 0,
+                        Destination::Workflow(name) => wf = &workflows[name.as_str()],
+                    }
+                }
+            })
+            .sum()
+    }
+}
+
+impl PartTwo for Day19 {
+    type Answer2 = u64;
+
+    fn part2((workflows, _): &Self::Input) -> u64 {
+        let ratings: IntervalSet<u64> = Interval::new(1, 4000).into();
+        let parts = PartRange {
+            x: ratings.clone(),
+            m: ratings.clone(),
+            a: ratings.clone(),
+            s: ratings,
+        };
+        accepted_in_part_range(workflows, &workflows["in"], parts)
+    }
+}
+
+fn compute_destination(
+    workflows: &HashMap<String, Workflow>,
+    parts: PartRange,
+    dest: &Destination,
+) -> u64 {
+    match &dest {
+        Destination::Accept => parts.sum(),
+        Destination::Reject => 0,
+        Destination::Workflow(name) => {
+            accepted_in_part_range(workflows, &workflows[name.as_str()], parts)
+        }
+    }
+}
+
+fn accepted_in_part_range(
+    workflows: &HashMap<String, Workflow>,
+    workflow: &Workflow,
+    parts: PartRange,
+) -> u64 {
+    let (untouched_parts, total) =
+        workflow
+            .rules
+            .iter()
+            .fold((parts, 0), |(parts, total), rule| {
+                let (rule_ranges, remaining) = parts.split(rule.cat, rule.compare);
+                (
+                    remaining,
+                    total + compute_destination(workflows, rule_ranges, &rule.dest),
+                )
+            });
+    total + compute_destination(workflows, untouched_parts, &workflow.dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day19, 19);
+
+    #[test]
+    fn test_bad_workflows() {
+        let text = "in{x>10:aa,R}\naa{m>5:in,A}\n";
+        let err = read_workflows(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 8: workflow \"in\" leads back to itself"
+        );
+        let text = "in{x>10:in,R}\n";
+        assert!(read_workflows(text).is_err());
+
+        let text = "in{x>10:aa,R}\naa{m>5:R,A}\naa{m>5:A,R}\n";
+        let err = read_workflows(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: duplicate workflow \"aa\""
+        );
+
+        // Two ways to the same workflow is fine.
+        let text = "in{x>10:aa,bb}\naa{m>5:bb,A}\nbb{a>5:R,A}\n";
+        assert!(read_workflows(text).is_ok());
+    }
+
+    #[test]
+    fn test_out_of_range_rules() {
+        let text = "in{x>4000:R,m<0:R,A}\n\n{x=1,m=2,a=3,s=4}\n";
+        let input = Day19::parse(text).unwrap();
+        assert_eq!(Day19::part1(&input), 10);
+        assert_eq!(Day19::part2(&input), 4000_u64.pow(4));
+
+        let text = "in{x>4000:A,m<0:A,R}\n\n{x=1,m=2,a=3,s=4}\n";
+        let input = Day19::parse(text).unwrap();
+        assert_eq!(Day19::part1(&input), 0);
+        assert_eq!(Day19::part2(&input), 0);
+    }
+}
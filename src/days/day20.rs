@@ -10,7 +10,8 @@ use `RefCell` to make a portion of a struct mutable for the first time here
 (history).  I reworked the implementation to use a graph, which is a lot cleaner
 (and sadely avoids the `RefCell`). This is a bit more complex than it it needs
 to be (`Node` could be removed) but it allows a Dot graph (see history for
-hand-implemented mermaid graph).
+hand-implemented mermaid graph). Part 1 presses the button with the `cycle`
+module, so a network that resets only simulates one loop.
 */
 
 use core::fmt::{Debug, Formatter};
@@ -24,22 +25,27 @@ use petgraph::{
     graph::NodeIndex,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Pulse {
+use crate::cycle::History;
+use crate::number_theory::lcm_all;
+use crate::parse::split_once;
+use crate::{ParseError, PartTwo, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pulse {
     High,
     Low,
 }
 
-#[derive(Debug)]
-enum Module {
+#[derive(Debug, Clone)]
+pub enum Module {
     FlipFlop(bool),
     Conjunction(HashMap<NodeIndex<u32>, Pulse>),
     Broadcaster,
     Output((usize, usize)),
 }
 
-#[derive(Constructor)]
-struct Node {
+#[derive(Clone, Constructor)]
+pub struct Node {
     name: String,
     module: Module,
 }
@@ -56,7 +62,7 @@ impl Debug for Node {
     }
 }
 
-type ModuleGraph = Graph<Node, ()>;
+pub type ModuleGraph = Graph<Node, ()>;
 
 impl Module {
     fn process_pulse(&mut self, pulse: Pulse, sender: NodeIndex) -> Option<Pulse> {
@@ -89,25 +95,38 @@ impl Module {
         }
     }
 
-    fn is_reset(&self) -> bool {
-        match *self {
-            Self::FlipFlop(ref flipflop) => !flipflop,
-            Self::Conjunction(ref conj) => conj.values().all(|x| *x == Pulse::Low),
-            _ => true,
+    /// What the module remembers, in a fixed order. Outputs only count
+    /// pulses, so they have no memory.
+    fn memory(&self) -> Vec<Pulse> {
+        match self {
+            Self::FlipFlop(true) => vec![Pulse::High],
+            Self::FlipFlop(false) => vec![Pulse::Low],
+            Self::Conjunction(conj) => conj
+                .iter()
+                .sorted_by_key(|(sender, _)| **sender)
+                .map(|(_, pulse)| *pulse)
+                .collect(),
+            Self::Broadcaster | Self::Output(_) => Vec::new(),
         }
     }
 }
 
-fn read_input(text: &str) -> ModuleGraph {
+fn read_input(text: &str) -> Result<ModuleGraph, ParseError> {
     let mut graph = ModuleGraph::new();
     let info: Vec<(&str, Vec<&str>)> = text
         .lines()
         .map(|line| {
-            let (inp, output) = line.split_once(" -> ").unwrap();
+            let (inp, output) = split_once(line, " -> ")?;
+            if !(inp.starts_with(['%', '&']) || inp == "broadcaster") {
+                return Err(ParseError::at(
+                    inp,
+                    format!("expected a `%` or `&` module or `broadcaster`, found {inp:?}"),
+                ));
+            }
             let output = output.split(", ").collect();
-            (inp, output)
+            Ok((inp, output))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     let all_output_nodes: HashSet<&str> = info.iter().flat_map(|(_, n)| n.clone()).collect();
     let flip_flop_nodes: HashSet<&str> = info
@@ -118,7 +137,11 @@ fn read_input(text: &str) -> ModuleGraph {
         .iter()
         .filter_map(|(n, _)| n.strip_prefix('&'))
         .collect();
-    let in_nodes = HashSet::from([info.iter().find(|(x, _)| *x == "broadcaster").unwrap().0]);
+    let in_nodes = HashSet::from([info
+        .iter()
+        .find(|(x, _)| *x == "broadcaster")
+        .ok_or_else(|| ParseError::new("expected a broadcaster"))?
+        .0]);
     let all_input_nodes = &(&flip_flop_nodes | &conjunction_nodes) | &in_nodes;
     let output_only_nodes = &all_output_nodes - &all_input_nodes;
 
@@ -168,7 +191,7 @@ fn read_input(text: &str) -> ModuleGraph {
         }
     }
 
-    graph
+    Ok(graph)
 }
 
 fn compute_press(node_graph: &mut ModuleGraph) -> (u64, u64) {
@@ -202,129 +225,106 @@ fn compute_press(node_graph: &mut ModuleGraph) -> (u64, u64) {
     (high_count, low_count)
 }
 
-fn measure_cycle(node_graph: &mut ModuleGraph, len: usize) -> (Option<u64>, u64, u64) {
-    let mut high_count = 0;
-    let mut low_count = 0;
-    let mut cycles = 0;
-    for _ in 0..len {
-        let (high, low) = compute_press(node_graph);
-        log::debug!("Loop {cycles}");
-        high_count += high;
-        low_count += low;
-        cycles += 1;
-        if node_graph.node_weights().all(|x| x.module.is_reset()) {
-            return (Some(cycles), high_count, low_count);
-        }
-    }
-    (None, high_count, low_count)
+/// Press the button up to `presses` times, stopping early if the modules
+/// come back to a state they were in before. Each state is the modules after
+/// a press, with the (high, low) pulses that press sent; the counts are part
+/// of the key, since they belong to the press and not to the memory.
+fn measure_cycle(node_graph: &ModuleGraph, presses: usize) -> History<(ModuleGraph, (u64, u64))> {
+    History::find_by_key(
+        (node_graph.clone(), (0, 0)),
+        |(node_graph, _)| {
+            let mut node_graph = node_graph.clone();
+            let counts = compute_press(&mut node_graph);
+            (node_graph, counts)
+        },
+        |(node_graph, counts)| {
+            let memory = node_graph
+                .node_weights()
+                .map(|node| node.module.memory())
+                .collect::<Vec<_>>();
+            (memory, *counts)
+        },
+        presses,
+    )
 }
 
-fn compute1(text: &str) -> u64 {
-    let mut node_map = read_input(text);
-    let (cycles, high, low) = measure_cycle(&mut node_map, 1000);
-    log::debug!("cycles: {cycles:?}, high: {high}, low: {low}");
-    cycles.map_or(high * low, |cycles| {
-        // `high`/`low` cover one reset cycle; scale to 1000 presses.
-        assert_eq!(1000 % cycles, 0, "cycle length must divide 1000");
-        let n = 1000 / cycles;
-        (high * n) * (low * n)
-    })
-}
+pub struct Day20;
 
-fn print_node_map(node_graph: &ModuleGraph) {
-    use petgraph::dot::{Config, Dot};
-    println!(
-        "{:?}",
-        Dot::with_config(&node_graph, &[Config::EdgeNoLabel])
-    );
-}
+impl Solution for Day20 {
+    type Input = ModuleGraph;
+    type Answer1 = u64;
 
-fn compute2(text: &str) -> u64 {
-    let node_graph = read_input(text);
-    println!("Assuming certain structure, we can simplify");
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_input(text)
+    }
 
-    let (broadcast,) = node_graph.externals(Incoming).collect_tuple().unwrap();
-    let mut total = Vec::new();
-
-    for node_idx in node_graph.neighbors_directed(broadcast, Outgoing) {
-        let mut node = node_idx;
-        let mut number = 0;
-        for i in 0.. {
-            let children: Vec<NodeIndex> = node_graph.neighbors_directed(node, Outgoing).collect();
-            let flip_flops: Vec<NodeIndex> = children
-                .iter()
-                .filter(|x| matches!(node_graph[**x].module, Module::FlipFlop { .. }))
-                .copied()
-                .collect();
-            match flip_flops.len() {
-                0 => {
-                    number += 1 << i;
-                    break;
-                }
-                1 => {
-                    if children.len() > 1 {
+    fn part1(input: &Self::Input) -> u64 {
+        let history = measure_cycle(input, 1000);
+        log::debug!("cycle: {:?}", history.cycle());
+        let (high, low) = (1..=1000)
+            .filter_map(|press| history.state_at(press))
+            .fold((0, 0), |(high, low), (_, counts)| {
+                (high + counts.0, low + counts.1)
+            });
+        high * low
+    }
+}
+
+impl PartTwo for Day20 {
+    type Answer2 = u64;
+
+    fn part2(node_graph: &Self::Input) -> u64 {
+        log_node_map(node_graph);
+        log::info!("Assuming certain structure, we can simplify");
+
+        let (broadcast,) = node_graph.externals(Incoming).collect_tuple().unwrap();
+        let mut total = Vec::new();
+
+        for node_idx in node_graph.neighbors_directed(broadcast, Outgoing) {
+            let mut node = node_idx;
+            let mut number = 0;
+            for i in 0.. {
+                let children: Vec<NodeIndex> =
+                    node_graph.neighbors_directed(node, Outgoing).collect();
+                let flip_flops: Vec<NodeIndex> = children
+                    .iter()
+                    .filter(|x| matches!(node_graph[**x].module, Module::FlipFlop { .. }))
+                    .copied()
+                    .collect();
+                match flip_flops.len() {
+                    0 => {
                         number += 1 << i;
+                        break;
                     }
-                    node = flip_flops[0];
+                    1 => {
+                        if children.len() > 1 {
+                            number += 1 << i;
+                        }
+                        node = flip_flops[0];
+                    }
+                    _ => unreachable!(),
                 }
-                _ => unreachable!(),
             }
+            total.push(number);
         }
-        total.push(number);
-    }
-    for n in &total {
-        print!("{n:b} ");
+        log::debug!("{}", total.iter().map(|n| format!("{n:b}")).join(" "));
+        log::info!("Counter periods: {total:?}");
+        lcm_all(total).expect("the counters only line up past u64::MAX")
     }
-    println!();
-    println!("Part 2: {total:?}");
-    total.iter().product()
 }
 
-fn main() {
-    env_logger::init();
-    let text = std::fs::read_to_string("input/20.txt").unwrap();
-    let node_map = read_input(&text);
-    println!("DOT graph:");
-    println!();
-    print_node_map(&node_map);
-    println!();
-
-    let result = compute1(&text);
-    println!("First = {result}");
-
-    let result = compute2(&text);
-    println!("Second = {result}");
+/// Log the module graph at debug level, in DOT format.
+fn log_node_map(node_graph: &ModuleGraph) {
+    use petgraph::dot::{Config, Dot};
+    log::debug!(
+        "DOT graph:\n{:?}",
+        Dot::with_config(&node_graph, &[Config::EdgeNoLabel])
+    );
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use test_log::test;
-
-    const INPUT: &str = "\
-broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a
-";
-
-    const INPUT2: &str = "\
-broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output";
-
-    #[test]
-    fn test_first() {
-        let result = compute1(INPUT);
-        assert_eq!(result, 32_000_000);
-    }
 
-    #[test]
-    fn test_second() {
-        let result = compute1(INPUT2);
-        assert_eq!(result, 11_687_500);
-    }
+    crate::example_tests!(Day20, 20);
 }
@@ -0,0 +1,218 @@
+/*!
+# 2023 Day 21: Step Counter
+## Garden maps
+
+<https://adventofcode.com/2023/day/21>
+
+Part 1 was pretty easy, just avoiding mistakes was tricky. I basically just
+do a convolution map [0 1 0; 1 0 1; 0 1 0] by hand. Part 2 was tricky, and
+had to look around for a bit of insperation. I basically just expand and
+compute the first couple of board-lenths (131), and then solve; the puzzle
+boards are all clear around the diagonals and edges, so each expansion adds
+the same amount (partially squared) to the total.
+
+This was rewritten to avoid copying the mask and to avoid grid copies too.  It
+is much, much faster than the original version, and supports arbitrary numbers of
+steps.
+*/
+
+use grid::Grid;
+
+use crate::grid_helper::{Color, GridExt, Position, Renderer, find_marker, parse_grid_with};
+use crate::{ParseError, PartTwo, Solution};
+
+/// This could be a inline function with `impl TryFrom<isize>`, but making it
+/// a macro allows us to reuse the name, which is fun. This also puts the
+/// error message in the right spot.
+macro_rules! isize {
+    ($x:expr) => {
+        isize::try_from($x).unwrap()
+    };
+}
+
+macro_rules! usize {
+    ($x:expr) => {
+        usize::try_from($x).unwrap()
+    };
+}
+
+fn read(text: &str) -> Result<(Grid<bool>, (usize, usize)), ParseError> {
+    // The start is an ordinary garden plot.
+    let mask = parse_grid_with(text, |x| match x {
+        '#' => Some(false),
+        '.' | 'S' => Some(true),
+        _ => None,
+    })?;
+
+    let start = find_marker(text, 'S')?;
+    if start.pos != (mask.rows() / 2, mask.cols() / 2) {
+        return Err(start.error("the start must be in the middle"));
+    }
+    Ok((mask, start.pos))
+}
+
+#[must_use]
+fn steps(mask: &Grid<bool>, start: &(usize, usize), dist: usize) -> Grid<bool> {
+    let mut grid: Grid<bool> = Grid::new(2 * dist + 1, 2 * dist + 1);
+    let midpoint = (isize!(grid.rows() - 1) / 2, isize!(grid.cols() - 1) / 2);
+    let start = (isize!(start.0), isize!(start.1));
+    grid[(usize!(midpoint.0), usize!(midpoint.1))] = true;
+    for i in 0..isize!(dist) {
+        // y, x are distances from the center, in grid coords
+        for y in -i..=i {
+            for x in -i..=i {
+                // In order to avoid copies, we can use a checkerboard pattern
+                if i % 2 != (x + y).rem_euclid(2) {
+                    continue;
+                }
+                // yc, xc are distance from the corner, grid coords
+                let yc = y + midpoint.0;
+                let xc = x + midpoint.1;
+                // If this value is true, we swap with four surrounding values
+                if *grid.get(yc, xc).unwrap() {
+                    for (dy, dx) in &[(0, 1), (0, -1), (1, 0), (-1, 0)] {
+                        // ty, tx are the new values, in grid coords
+                        let ty = yc + dy;
+                        let tx = xc + dx;
+
+                        // The new values in mask coords; the garden repeats
+                        // forever, so the mask wraps around
+                        let m = Position::new(ty + start.0 - midpoint.0, tx + start.1 - midpoint.1);
+
+                        if *mask.get_wrapping(m) {
+                            grid[(usize!(ty), usize!(tx))] = true;
+                            grid[(usize!(yc), usize!(xc))] = false;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    grid
+}
+
+#[must_use]
+fn count_locations(grid: &Grid<bool>) -> usize {
+    grid.iter().filter(|x| **x).count()
+}
+
+/// Log the reachable plots at debug level, centered on the start.
+fn log_grid(mask: &Grid<bool>, grid: &Grid<bool>, start: &(usize, usize)) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+    let midpoint = ((grid.rows() - 1) / 2, (grid.cols() - 1) / 2);
+    let mut picture = Grid::new(grid.rows(), grid.cols());
+    for ((yc, xc), c) in picture.indexed_iter_mut() {
+        let m = Position::new(
+            isize!(yc + start.0) - isize!(midpoint.0),
+            isize!(xc + start.1) - isize!(midpoint.1),
+        );
+        *c = match (mask.get_wrapping(m), grid[(yc, xc)]) {
+            (false, false) => '#',
+            (true, false) => '.',
+            (true, true) => 'O',
+            (false, true) => panic!("Can't exist in both at {m:?}!"),
+        };
+    }
+    picture[midpoint] = 'S';
+    let picture = Renderer::new(&picture)
+        .highlight(grid, Color::Green)
+        .color_for(&std::io::stderr());
+    log::debug!("\n{picture}");
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Grid<bool>, (usize, usize));
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read(text)
+    }
+
+    fn part1((mask, start): &Self::Input) -> usize {
+        let grid = steps(mask, start, 64);
+        log_grid(mask, &grid, start);
+        count_locations(&grid)
+    }
+}
+
+impl PartTwo for Day21 {
+    type Answer2 = usize;
+
+    fn part2((mask, start): &Self::Input) -> usize {
+        let val = 26_501_365;
+        log::info!("Going to compute for steps = {val}");
+
+        let grid = steps(mask, start, 65);
+        let y_0 = count_locations(&grid);
+        log::info!("131*0 = 65 = {y_0}");
+
+        let grid = steps(mask, start, 65 + 131);
+        let y_1 = count_locations(&grid);
+        log::info!("131*1 + 65 = {y_1}");
+
+        let grid = steps(mask, start, 65 + 131 * 2);
+        let y_2 = count_locations(&grid);
+        log::info!("131*2 + 65 = {y_2}");
+
+        let a2 = y_2 - 2 * y_1 + y_0;
+        let b2 = 4 * y_1 - 3 * y_0 - y_2;
+        let c = y_0;
+
+        log::info!("{a2}/2 x^2 +{b2}/2 x + {c} = y");
+        log::info!("x=0, y={c}");
+        log::info!("x=1, y={}", usize::midpoint(a2, b2) + c);
+        log::info!("x=2, y={}", usize::midpoint(4 * a2, 2 * b2) + c);
+        let result = usize::midpoint(202_300 * 202_300 * a2, 202_300 * b2) + c;
+        log::info!("x=202300, y={result}");
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    crate::example_tests!(Day21, 21);
+
+    const INPUT: &str = include_str!("../../examples/21/example.txt");
+
+    #[test]
+    fn test_first() {
+        let (mask, start) = read(INPUT).unwrap();
+        let grid = steps(&mask, &start, 6);
+        let result = count_locations(&grid);
+        log_grid(&mask, &grid, &start);
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn test_second() {
+        let (mask, start) = read(INPUT).unwrap();
+        let grid = steps(&mask, &start, 10);
+        let result = count_locations(&grid);
+        assert_eq!(result, 50);
+    }
+
+    #[test]
+    fn test_third() {
+        let (mask, start) = read(INPUT).unwrap();
+        let grid = steps(&mask, &start, 50);
+        let result = count_locations(&grid);
+        assert_eq!(result, 1594);
+    }
+
+    #[test]
+    fn test_off_center() {
+        let text = "...\n..S\n...\n";
+        let err = read(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: the start must be in the middle"
+        );
+    }
+}
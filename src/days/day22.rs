@@ -0,0 +1,207 @@
+/*!
+# 2023 Day 22: Sand Slabs
+## 3D falling blocks
+
+<https://adventofcode.com/2023/day/22>
+
+First iteration used intervallum, but I reworked this for a cleaner approach and
+a custom interval class. I wanted sorting by lower edges and an easy way to get
+the minimum bound. Intervallum for some reason doesn't have public access to
+`::new()` or `::low()`. Ideally I also wanted to be able to shift an interval, too.
+That class grew into the `interval` module, which days 5 and 19 use as well, and
+a block is now a `Cuboid` from the `geometry` module.
+
+Plotting code for Blender:
+
+```python
+import bpy
+import bmesh
+from mathutils import Matrix
+import numpy as np
+
+
+TXT = """\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9"""
+
+def add_object(bm, start, end):
+    scale = np.abs(start - end) + 1
+    matrix = Matrix.LocRotScale((start + end) / 2, None, scale)
+    bmesh.ops.create_cube(bm, size=1.0, matrix=matrix)
+
+
+bm = bmesh.new()
+
+for line in TXT.splitlines():
+    ax, ay, az, bx, by, bz = map(float, line.replace("~", ",").split(","))
+    add_object(bm, np.array((ax, ay, az)), np.array((bx, by, bz)))
+
+me = bpy.data.meshes.new("Mesh")
+bm.to_mesh(me)
+bm.free()
+
+obj = bpy.data.objects.new("Object", me)
+bpy.context.collection.objects.link(obj)
+```
+
+*/
+
+use itertools::Itertools;
+
+use crate::geometry::{Cuboid, Vec3};
+use crate::parse::{number, split_once};
+use crate::{ParseError, PartTwo, Solution};
+
+/// A brick, from one corner to the other as (x, y, z).
+pub type Block = Cuboid<usize>;
+
+fn block(corner_1: (usize, usize, usize), corner_2: (usize, usize, usize)) -> Block {
+    Cuboid::new(corner_1.into(), corner_2.into())
+}
+
+/// The height of the top of `below`, if it is under `block`.
+fn high_point(below: &Block, block: &Block) -> Option<usize> {
+    below.overlaps_xy(block).then(|| below.z().high())
+}
+
+fn get_blocks_above<'a>(block: &Block, blocks: &'a [Block]) -> Vec<&'a Block> {
+    let one_up = *block + Vec3::new(0, 0, 1);
+    blocks
+        .iter()
+        .filter(|x| *x != block && one_up.overlaps(x))
+        .collect()
+}
+
+fn count_supports(block: &Block, blocks: &[Block]) -> usize {
+    let one_down = *block - Vec3::new(0, 0, 1);
+    blocks
+        .iter()
+        .filter(|x| *x != block)
+        .filter(|x| one_down.overlaps(x))
+        .count()
+}
+
+/// Sort from the ground up, so each block only lands on ones before it.
+fn sort_blocks(blocks: &mut [Block]) {
+    blocks.sort_by_key(|b| (b.z(), b.y(), b.x()));
+}
+
+/// Drop a block down to `level`.
+fn drop_to(block: &mut Block, level: usize) {
+    *block = *block - Vec3::new(0, 0, block.z().low() - level);
+}
+
+fn lower_blocks(blocks: &mut [Block]) {
+    drop_to(&mut blocks[0], 1);
+    for i in 1..blocks.len() {
+        let (blocks_below, blocks_above) = blocks.split_at_mut(i);
+        let block = &mut blocks_above[0];
+        let level = blocks_below
+            .iter()
+            .filter_map(|x| Some(high_point(x, block)? + 1))
+            .max()
+            .unwrap_or(1);
+        drop_to(block, level);
+    }
+}
+
+fn removable_blocks(blocks: &[Block]) -> Vec<&Block> {
+    blocks
+        .iter()
+        .filter(|x| {
+            let above = get_blocks_above(x, blocks);
+            if above.is_empty() {
+                return true;
+            }
+            above.iter().all(|y| count_supports(y, blocks) > 1)
+        })
+        .collect()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Block>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        let mut blocks = read(text)?;
+        sort_blocks(&mut blocks);
+        Ok(blocks)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let mut blocks = input.clone();
+        lower_blocks(&mut blocks);
+        removable_blocks(&blocks).len()
+    }
+}
+
+impl PartTwo for Day22 {
+    type Answer2 = usize;
+
+    fn part2(input: &Self::Input) -> usize {
+        let mut blocks = input.clone();
+        lower_blocks(&mut blocks);
+        blocks
+            .iter()
+            .map(|b| {
+                let mut new_blocks: Vec<Block> =
+                    blocks.iter().filter(|x| *x != b).copied().collect();
+                lower_blocks(&mut new_blocks);
+                blocks
+                    .iter()
+                    .filter(|x| *x != b)
+                    .zip(new_blocks.iter())
+                    .filter(|(x, y)| **x != **y)
+                    .count()
+            })
+            .sum()
+    }
+}
+
+fn read_corner(text: &str) -> Result<(usize, usize, usize), ParseError> {
+    text.split(',')
+        .map(number)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::at(text, "expected three coordinates"))
+}
+
+fn read(text: &str) -> Result<Vec<Block>, ParseError> {
+    text.lines()
+        .map(|line| {
+            let (a, b) = split_once(line, "~")?;
+            Ok(block(read_corner(a)?, read_corner(b)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests!(Day22, 22);
+
+    const INPUT: &str = include_str!("../../examples/22/example.txt");
+
+    #[test]
+    fn test_parts_1() {
+        let mut blocks = read(INPUT).unwrap();
+        assert_eq!(blocks.len(), 7);
+
+        sort_blocks(&mut blocks);
+        println!("{blocks:?}");
+        assert_eq!(blocks[0], block((1, 0, 1), (1, 2, 1)));
+
+        lower_blocks(&mut blocks);
+        println!("{blocks:?}");
+        assert_eq!(blocks[6], block((1, 1, 5), (1, 1, 6)));
+    }
+}
@@ -60,11 +60,13 @@ use itertools::Itertools;
 use petgraph::algo::all_simple_paths;
 use petgraph::graph::Graph;
 
-fn read_grid(text: &str) -> Grid<char> {
-    text.lines()
-        .map(|line| line.chars().collect())
-        .collect::<Vec<Vec<char>>>()
-        .into()
+use crate::grid_helper::parse_grid_with;
+use crate::{ParseError, PartTwo, Solution};
+
+fn read_grid(text: &str) -> Result<Grid<char>, ParseError> {
+    parse_grid_with(text, |c| {
+        matches!(c, '#' | '.' | '<' | '>' | '^' | 'v').then_some(c)
+    })
 }
 
 fn make_graph_directed(grid: &Grid<char>) -> Graph<(usize, usize), usize> {
@@ -172,79 +174,50 @@ fn longest_path_length(graph: &Graph<(usize, usize), usize>, grid: &Grid<char>)
     *paths.iter().max().unwrap()
 }
 
-fn compute1(text: &str) -> usize {
-    let grid = read_grid(text);
-    let graph = make_graph_directed(&grid);
-    let graph = simplify_graph(graph);
-    print_graph(&graph);
-    longest_path_length(&graph, &grid)
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read_grid(text)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        let graph = make_graph_directed(grid);
+        let graph = simplify_graph(graph);
+        log_graph(&graph);
+        longest_path_length(&graph, grid)
+    }
 }
 
-fn compute2(text: &str) -> usize {
-    let mut grid = read_grid(text);
-    for c in grid.iter_mut() {
-        if matches!(*c, '<' | '>' | '^' | 'v') {
-            *c = '.';
+impl PartTwo for Day23 {
+    type Answer2 = usize;
+
+    fn part2(input: &Self::Input) -> usize {
+        let mut grid = input.clone();
+        for c in grid.iter_mut() {
+            if matches!(*c, '<' | '>' | '^' | 'v') {
+                *c = '.';
+            }
         }
+        let graph = make_graph_directed(&grid);
+        let graph = simplify_graph(graph);
+        log_graph(&graph);
+        longest_path_length(&graph, &grid)
     }
-    let graph = make_graph_directed(&grid);
-    let graph = simplify_graph(graph);
-    print_graph(&graph);
-    longest_path_length(&graph, &grid)
 }
 
-fn print_graph<T: core::fmt::Debug>(graph: &Graph<T, usize>) {
+/// Log the simplified graph at debug level, in DOT format.
+fn log_graph<T: core::fmt::Debug>(graph: &Graph<T, usize>) {
     use petgraph::dot::Dot;
-    print!("{:?}", Dot::with_config(&graph, &[]));
-}
-
-fn main() {
-    let text = std::fs::read_to_string("input/23.txt").unwrap();
-    let result = compute1(&text);
-    println!("First = {result}");
-
-    let result = compute2(&text);
-    println!("Second = {result}");
+    log::debug!("DOT graph:\n{:?}", Dot::with_config(&graph, &[]));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-#.#####################
-#.......#########...###
-#######.#########.#.###
-###.....#.>.>.###.#.###
-###v#####.#v#.###.#.###
-###.>...#.#.#.....#...#
-###v###.#.#.#########.#
-###...#.#.#.......#...#
-#####.#.#.#######.#.###
-#.....#.#.#.......#...#
-#.#####.#.#.#########v#
-#.#...#...#...###...>.#
-#.#.#v#######v###.###v#
-#...#.>.#...>.>.#.###.#
-#####v#.#.###v#.#.###.#
-#.....#...#...#.#.#...#
-#.#########.###.#.#.###
-#...###...#...#...#.###
-###.###.#.###v#####v###
-#...#...#.#.>.>.#.>.###
-#.###.###.#.###.#.#v###
-#.....###...###...#...#
-#####################.#";
-
-    #[test]
-    fn test_first() {
-        let result = compute1(INPUT);
-        assert_eq!(result, 94);
-    }
-
-    #[test]
-    fn test_second() {
-        let result = compute2(INPUT);
-        assert_eq!(result, 154);
-    }
+    crate::example_tests!(Day23, 23);
 }
@@ -6,6 +6,7 @@
 
 Parts 1 and 2 are really different here. The key trick to part 1 is
 understanding that the time parameter must be positive for both trajectories.
+Positions and velocities are `Vec3`s from the `geometry` module.
 
 
 ```python
@@ -81,64 +82,54 @@ print(answer)
 use itertools::Itertools;
 use std::collections::HashSet;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Vector<T> {
-    x: T,
-    y: T,
-    z: T,
-}
+use crate::geometry::Vec3;
+use crate::parse::{number, split_once};
+use crate::{ParseError, PartTwo, Solution};
 
-impl From<Vector<i64>> for Vector<f64> {
-    fn from(value: Vector<i64>) -> Self {
-        Self {
-            x: value.x as f64,
-            y: value.y as f64,
-            z: value.z as f64,
-        }
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    p: Vec3<i64>,
+    d: Vec3<i64>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Line {
-    p: Vector<i64>,
-    d: Vector<i64>,
+fn read_vector(text: &str) -> Result<Vec3<i64>, ParseError> {
+    text.split(',')
+        .map(|n| number(n.trim()))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .collect_tuple()
+        .map(|(x, y, z)| Vec3::new(x, y, z))
+        .ok_or_else(|| ParseError::at(text, "expected three coordinates"))
 }
 
-fn read(text: &str) -> Vec<Line> {
+fn read(text: &str) -> Result<Vec<Line>, ParseError> {
     text.lines()
         .map(|line| {
-            let (x, y, z, dx, dy, dz) = line
-                .replace('@', ",")
-                .replace(' ', "")
-                .split(',')
-                .map(|n| n.parse().unwrap())
-                .collect_tuple()
-                .unwrap();
-            Line {
-                p: Vector { x, y, z },
-                d: Vector {
-                    x: dx,
-                    y: dy,
-                    z: dz,
-                },
-            }
+            let (p, d) = split_once(line, "@")?;
+            Ok(Line {
+                p: read_vector(p)?,
+                d: read_vector(d)?,
+            })
         })
         .collect()
 }
 
+/// Where the paths cross in x and y, and the times `(q, t)` that `b` and `a`
+/// get there. The `z` of a cross product is the 2D cross product, so both
+/// parts of `q` can be found exactly before dividing.
 #[allow(clippy::suboptimal_flops)]
 fn intersect_2d(a: &Line, b: &Line) -> Option<(f64, f64, f64, f64)> {
-    let ad: Vector<f64> = a.d.clone().into();
-    let bd: Vector<f64> = b.d.clone().into();
-    let ap: Vector<f64> = a.p.clone().into();
-    let bp: Vector<f64> = b.p.clone().into();
+    let ad = a.d.map(|v| v as f64);
+    let bd = b.d.map(|v| v as f64);
+    let ap = a.p.map(|v| v as f64);
+    let bp = b.p.map(|v| v as f64);
 
-    let top = ad.y * bp.x - ad.y * ap.x + ad.x * ap.y - ad.x * bp.y;
-    let bot = a.d.x * b.d.y - a.d.y * b.d.x;
+    let top = (b.p - a.p).cross(a.d).z;
+    let bot = a.d.cross(b.d).z;
     if bot == 0 {
         return None;
     }
-    let q = top / bot as f64;
+    let q = top as f64 / bot as f64;
     let t = if a.d.x == 0 {
         (ap.x + q * ad.x - bp.x) / bd.x
     } else {
@@ -152,12 +143,8 @@ fn intersect_2d(a: &Line, b: &Line) -> Option<(f64, f64, f64, f64)> {
 fn intersect_2d_in(vals: &[Line], low: i64, high: i64) -> impl Iterator<Item = (f64, f64)> + '_ {
     vals.iter()
         .map(move |a| Line {
-            p: Vector::<i64> {
-                x: a.p.x - low,
-                y: a.p.y - low,
-                z: a.p.z,
-            },
-            d: a.d.clone(),
+            p: a.p - Vec3::new(low, low, 0),
+            d: a.d,
         })
         .combinations(2)
         .filter_map(move |pair| {
@@ -177,9 +164,8 @@ fn intersect_2d_in(vals: &[Line], low: i64, high: i64) -> impl Iterator<Item = (
         })
 }
 
-fn compute1(text: &str, low: i64, high: i64) -> usize {
-    let hail_lines = read(text);
-    intersect_2d_in(&hail_lines, low, high).count()
+fn count_intersections(hail_lines: &[Line], low: i64, high: i64) -> usize {
+    intersect_2d_in(hail_lines, low, high).count()
 }
 
 fn find_velocity(vals: &[Line]) -> (i64, i64, i64) {
@@ -251,37 +237,43 @@ fn find_position(vals: &[Line], dx: i64, dy: i64, dz: i64) -> (i64, i64, i64) {
     )
 }
 
-fn compute2(text: &str) -> i64 {
-    let hail_lines = read(text);
-    let (dx, dy, dz) = find_velocity(&hail_lines);
-    let (px, py, pz) = find_position(&hail_lines, dx, dy, dz);
-    println!("Found ({px}, {py}, {pz}) + t*({dx}, {dy}, {dz})");
-    px + py + pz
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read(text)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        count_intersections(input, 200_000_000_000_000, 400_000_000_000_000)
+    }
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/24.txt").unwrap();
-    let result = compute1(&text, 200_000_000_000_000, 400_000_000_000_000);
-    println!("First = {result}");
+impl PartTwo for Day24 {
+    type Answer2 = i64;
 
-    let result = compute2(&text);
-    println!("Second = {result}");
+    fn part2(input: &Self::Input) -> i64 {
+        let (dx, dy, dz) = find_velocity(input);
+        let (px, py, pz) = find_position(input, dx, dy, dz);
+        log::info!("Found ({px}, {py}, {pz}) + t*({dx}, {dy}, {dz})");
+        px + py + pz
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-19, 13, 30 @ -2,  1, -2
-18, 19, 22 @ -1, -1, -2
-20, 25, 34 @ -2, -2, -4
-12, 31, 28 @ -1, -2, -1
-20, 19, 15 @  1, -5, -3";
+    crate::example_tests!(Day24, 24);
+
+    const INPUT: &str = include_str!("../../examples/24/example.txt");
 
     #[test]
     fn test_first() {
-        let result = compute1(INPUT, 7, 27);
+        let result = count_intersections(&read(INPUT).unwrap(), 7, 27);
         assert_eq!(result, 2);
     }
 }
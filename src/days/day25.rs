@@ -30,27 +30,33 @@ print(len(a), "*", len(b), "=", len(a) * len(b))
 use petgraph::graph::UnGraph;
 use rustworkx_core::connectivity::stoer_wagner_min_cut;
 
-fn read(text: &str) -> UnGraph<&str, ()> {
+use crate::parse::split_once;
+use crate::{ParseError, Solution};
+
+fn read(text: &str) -> Result<UnGraph<String, ()>, ParseError> {
     let mut graph = UnGraph::new_undirected();
     let mut nodes = std::collections::HashMap::new();
     for line in text.lines() {
-        let (node, edges) = line.split_once(": ").unwrap();
-        let node = *nodes.entry(node).or_insert_with(|| graph.add_node(node));
+        let (node, edges) = split_once(line, ": ")?;
+        let node = *nodes
+            .entry(node)
+            .or_insert_with(|| graph.add_node(node.to_string()));
         for edge in edges.split(' ') {
-            let edge = *nodes.entry(edge).or_insert_with(|| graph.add_node(edge));
+            let edge = *nodes
+                .entry(edge)
+                .or_insert_with(|| graph.add_node(edge.to_string()));
             graph.add_edge(node, edge, ());
         }
     }
-    graph
+    Ok(graph)
 }
 
-fn compute(text: &str) -> usize {
-    let graph = read(text);
-    let len = find_edges(&graph);
+fn compute(graph: &UnGraph<String, ()>) -> usize {
+    let len = find_edges(graph);
     len * (graph.node_count() - len)
 }
 
-fn find_edges(graph: &UnGraph<&str, ()>) -> usize {
+fn find_edges(graph: &UnGraph<String, ()>) -> usize {
     let (cut, items) = stoer_wagner_min_cut(graph, |_| Ok::<_, ()>(1))
         .unwrap()
         .unwrap();
@@ -58,34 +64,25 @@ fn find_edges(graph: &UnGraph<&str, ()>) -> usize {
     items.len()
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/25.txt").unwrap();
-    let result = compute(&text);
-    println!("Answer = {result}");
+pub struct Day25;
+
+/// Day 25 only has one puzzle; the registry never asks for a second part.
+impl Solution for Day25 {
+    type Input = UnGraph<String, ()>;
+    type Answer1 = usize;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        read(text)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        compute(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-jqt: rhn xhk nvd
-rsh: frs pzl lsr
-xhk: hfx
-cmg: qnr nvd lhk bvb
-rhn: xhk bvb hfx
-bvb: xhk hfx
-pzl: lsr hfx nvd
-qnr: nvd
-ntq: jqt hfx bvb xhk
-nvd: lhk
-lsr: lhk
-rzs: qnr cmg lsr rsh
-frs: qnr lhk lsr";
-
-    #[test]
-    fn test_first() {
-        let result = compute(INPUT);
-        assert_eq!(result, 54);
-    }
+    crate::example_tests!(Day25, 25, part1_only);
 }
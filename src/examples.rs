@@ -0,0 +1,148 @@
+/*!
+Example inputs from the puzzle texts, kept as test fixtures.
+
+Each example is a pair of files in `examples/NN/`: the puzzle text in
+`<case>.txt`, and its expected answers in `<case>.toml`, in the same format as
+the [recorded answers](crate::answers):
+
+```text
+examples/08/example1.txt
+examples/08/example1.toml    part1 = 2
+examples/08/example3.txt
+examples/08/example3.toml    part2 = 6
+```
+
+Only the parts listed are checked, since some examples only fit one part.
+[`example_tests!`](crate::example_tests) turns a day's examples into a test,
+so adding a case is just adding files.
+*/
+
+use std::io;
+use std::path::PathBuf;
+
+use crate::answers::{Answers, Outcome};
+use crate::days::Day;
+
+/// The directory of a day's examples, like `examples/08`.
+#[must_use]
+pub fn dir(number: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(format!("{number:02}"))
+}
+
+/// One example: its input and expected answers.
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// The case name, the file name without `.txt`.
+    pub name: String,
+    /// The puzzle text.
+    pub text: String,
+    /// The expected answers.
+    pub answers: Answers,
+}
+
+/// Load every example for a day, sorted by name.
+///
+/// # Errors
+///
+/// If the directory or a file can't be read, or an answers file is missing
+/// or invalid.
+pub fn load(number: u32) -> io::Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    for entry in std::fs::read_dir(dir(number))? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        let answers_path = path.with_extension("toml");
+        let answers = std::fs::read_to_string(&answers_path).map_err(|err| {
+            io::Error::new(err.kind(), format!("{}: {err}", answers_path.display()))
+        })?;
+        let answers = Answers::parse(&answers).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {err}", answers_path.display()),
+            )
+        })?;
+        examples.push(Example {
+            name,
+            text: std::fs::read_to_string(&path)?,
+            answers,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// Solve every example for a registered day, returning a description of each
+/// part whose answer is wrong (or whose input doesn't parse).
+///
+/// # Errors
+///
+/// If the examples can't be loaded.
+pub fn check(day: &Day) -> io::Result<Vec<String>> {
+    let mut failures = Vec::new();
+    for example in load(day.number)? {
+        let input = match (day.parse)(&example.text) {
+            Ok(input) => input,
+            Err(err) => {
+                failures.push(format!("{}: could not parse: {err}", example.name));
+                continue;
+            }
+        };
+        for part in 1..=2 {
+            if example.answers.part(part).is_none() {
+                continue;
+            }
+            let answer = match input.solve(part) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    failures.push(format!("{} part {part}: {err}", example.name));
+                    continue;
+                }
+            };
+            if let Outcome::Fail { expected } = example.answers.check(part, &answer) {
+                failures.push(format!(
+                    "{} part {part}: got {answer}, expected {expected}",
+                    example.name
+                ));
+            }
+        }
+    }
+    Ok(failures)
+}
+
+/// Generate a test that checks a day against all of its examples in
+/// `examples/NN/`. A day without a second part says so with `part1_only`.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     crate::example_tests!(Day08, 8);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $number:expr) => {
+        $crate::example_tests!(@check $crate::days::Day::new::<$solution>($number, ""));
+    };
+    ($solution:ty, $number:expr, part1_only) => {
+        $crate::example_tests!(@check $crate::days::Day::part1_only::<$solution>($number, ""));
+    };
+    (@check $day:expr) => {
+        #[test]
+        fn test_examples() {
+            let failures = $crate::examples::check(&$day)
+                .unwrap_or_else(|err| panic!("Could not load the examples: {err}"));
+            assert!(failures.is_empty(), "{}", failures.join("\n"));
+        }
+    };
+}
@@ -0,0 +1,255 @@
+/*!
+Integer geometry in three dimensions: a [`Vec3`] for points and directions,
+and a [`Cuboid`] for axis-aligned boxes.
+
+A `Vec3` adds and subtracts by component, scales by a number, and has the dot
+and cross products. A `Cuboid` covers a closed range of cells along each axis
+(one [`Interval`] per axis), like a falling brick, so two cuboids overlap when
+they share at least one cell.
+
+```ignore
+let brick = Cuboid::new(Vec3::new(0, 0, 2), Vec3::new(2, 0, 2));
+let below = brick - Vec3::new(0, 0, 1);
+assert!(!brick.overlaps(&below));
+```
+*/
+
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use derive_more::Constructor;
+use num::PrimInt;
+
+use crate::interval::Interval;
+
+/// A point or a direction in space.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Constructor,
+    derive_more::Add,
+    derive_more::Sub,
+)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    #[must_use]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The cross product, at right angles to both (following the right-hand
+    /// rule). Its `z` is the 2D cross product of the `x` and `y` parts.
+    #[must_use]
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+impl<T> Vec3<T> {
+    /// Apply `f` to each component, say to convert the type.
+    #[must_use]
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec3<U> {
+        Vec3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self.map(|value| value * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(Neg::neg)
+    }
+}
+
+/// An axis-aligned box of cells, including both corners.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T> {
+    x: Interval<T>,
+    y: Interval<T>,
+    z: Interval<T>,
+}
+
+impl<T: PrimInt> Cuboid<T> {
+    /// The box between two opposite corners, in either order.
+    #[must_use]
+    pub fn new(a: Vec3<T>, b: Vec3<T>) -> Self {
+        Self {
+            x: Interval::new(a.x, b.x),
+            y: Interval::new(a.y, b.y),
+            z: Interval::new(a.z, b.z),
+        }
+    }
+
+    #[must_use]
+    pub const fn x(&self) -> Interval<T> {
+        self.x
+    }
+
+    #[must_use]
+    pub const fn y(&self) -> Interval<T> {
+        self.y
+    }
+
+    #[must_use]
+    pub const fn z(&self) -> Interval<T> {
+        self.z
+    }
+
+    /// The corner with the smallest coordinates.
+    #[must_use]
+    pub const fn min(&self) -> Vec3<T> {
+        Vec3::new(self.x.low(), self.y.low(), self.z.low())
+    }
+
+    /// The corner with the largest coordinates.
+    #[must_use]
+    pub const fn max(&self) -> Vec3<T> {
+        Vec3::new(self.x.high(), self.y.high(), self.z.high())
+    }
+
+    /// The number of cells in the box.
+    #[must_use]
+    pub fn volume(&self) -> T {
+        self.x.size() * self.y.size() * self.z.size()
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        self.x.contains(point.x) && self.y.contains(point.y) && self.z.contains(point.z)
+    }
+
+    /// Whether the boxes share a cell.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.overlaps_xy(other) && !self.z.is_disjoint(&other.z)
+    }
+
+    /// Whether the boxes would share a cell if they were at the same height:
+    /// whether one is above the other, looking down.
+    #[must_use]
+    pub fn overlaps_xy(&self, other: &Self) -> bool {
+        !self.x.is_disjoint(&other.x) && !self.y.is_disjoint(&other.y)
+    }
+
+    /// The cells in both boxes, if there are any.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.intersection(&other.x)?,
+            y: self.y.intersection(&other.y)?,
+            z: self.z.intersection(&other.z)?,
+        })
+    }
+}
+
+/// Shows the ranges along each axis, like `(1, 0-2, 5)`.
+impl<T: Debug + PartialEq> Debug for Cuboid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
+    }
+}
+
+/// Moves the box by an offset.
+impl<T: PrimInt> Add<Vec3<T>> for Cuboid<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vec3<T>) -> Self {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+/// Moves the box back by an offset.
+impl<T: PrimInt> Sub<Vec3<T>> for Cuboid<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vec3<T>) -> Self {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec3() {
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::new(4, -5, 6);
+        assert_eq!(a + b, Vec3::new(5, -3, 9));
+        assert_eq!(a - b, Vec3::new(-3, 7, -3));
+        assert_eq!(a * 2, Vec3::new(2, 4, 6));
+        assert_eq!(-a, Vec3::new(-1, -2, -3));
+        assert_eq!(a.dot(b), 4 - 10 + 18);
+        assert_eq!(a.cross(b), Vec3::new(27, 6, -13));
+        assert_eq!(a.cross(b).dot(a), 0);
+        assert_eq!(a.cross(a), Vec3::default());
+        assert_eq!(a.map(f64::from), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let brick = Cuboid::new(Vec3::new(2, 0, 5), Vec3::new(0, 0, 5));
+        assert_eq!(brick.min(), Vec3::new(0, 0, 5));
+        assert_eq!(brick.max(), Vec3::new(2, 0, 5));
+        assert_eq!(brick.volume(), 3);
+        assert_eq!(format!("{brick:?}"), "(0-2, 0, 5)");
+        assert!(brick.contains(Vec3::new(1, 0, 5)));
+
+        let across = Cuboid::new(Vec3::new(1, 0, 4), Vec3::new(1, 2, 4));
+        assert!(!brick.overlaps(&across));
+        assert!(brick.overlaps_xy(&across));
+        assert!(brick.overlaps(&(across + Vec3::new(0, 0, 1))));
+        assert_eq!(
+            brick.intersection(&(across + Vec3::new(0, 0, 1))),
+            Some(Cuboid::new(Vec3::new(1, 0, 5), Vec3::new(1, 0, 5)))
+        );
+        assert_eq!(brick.intersection(&across), None);
+        assert_eq!(
+            brick - Vec3::new(0, 0, 5),
+            Cuboid::new(Vec3::new(0, 0, 0), Vec3::new(2, 0, 0))
+        );
+    }
+}
@@ -0,0 +1,586 @@
+/*!
+Helpers for 2D grid problems.
+
+Grids are read from text with [`parse_grid`] (for cell types that implement
+`TryFrom<char>`) or [`parse_grid_with`] (for a closure), both of which report
+unknown characters and ragged rows with their position. Marker cells, like the
+`S` for a start, are found with [`find_marker`].
+
+A [`Position`] steps by a [`Direction`] (or a [`Direction8`], diagonals
+included), and lists its neighbors with [`Position::neighbors4`] and
+[`Position::neighbors8`], or the `_in` versions that stay inside a grid.
+[`GridExt`] looks up positions without panicking, or wrapping around for an
+endless, tiled grid.
+
+For debugging, a [`Renderer`] draws a grid one row per line, with highlight
+layers (masks shown in a color) when writing to a terminal.
+*/
+
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Index, IndexMut};
+use std::io::{self, IsTerminal};
+
+use derive_more::Constructor;
+use grid::Grid;
+use strum::{EnumIter, IntoEnumIterator};
+
+use crate::ParseError;
+use crate::parse::char_at;
+
+/// This is a direction. Can be converted to a bitflags-like u8.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Direction {
+    Up = 0x01,
+    Down = 0x02,
+    Left = 0x04,
+    Right = 0x08,
+}
+
+impl Direction {
+    /// This rotates the direction clockwise.
+    #[must_use]
+    pub const fn clockwise(&self) -> Self {
+        use Direction::{Down, Left, Right, Up};
+
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    /// This rotates the direction counter-clockwise.
+    #[must_use]
+    pub const fn counter_clockwise(&self) -> Self {
+        use Direction::{Down, Left, Right, Up};
+
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
+}
+
+/// One of the eight directions to a neighboring cell, including diagonals.
+/// Iterates clockwise from `Up`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// The (row, column) step in this direction.
+    #[must_use]
+    pub const fn offset(self) -> (isize, isize) {
+        use Direction8::{Down, DownLeft, DownRight, Left, Right, Up, UpLeft, UpRight};
+
+        match self {
+            Up => (-1, 0),
+            UpRight => (-1, 1),
+            Right => (0, 1),
+            DownRight => (1, 1),
+            Down => (1, 0),
+            DownLeft => (1, -1),
+            Left => (0, -1),
+            UpLeft => (-1, -1),
+        }
+    }
+
+    /// Whether this is one of the four diagonals.
+    #[must_use]
+    pub const fn is_diagonal(self) -> bool {
+        let (dy, dx) = self.offset();
+        dy != 0 && dx != 0
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Self::Up,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+            Direction::Right => Self::Right,
+        }
+    }
+}
+
+/// This is a helper for a signed position. You can add a direction to step
+/// in that direction. You can try convert to a classic (usize, usize)
+/// position.
+#[derive(Debug, Constructor, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position(isize, isize);
+
+impl Position {
+    /// The row of this position, signed.
+    #[must_use]
+    pub const fn row(&self) -> isize {
+        self.0
+    }
+    /// The column of this position, signed.
+    #[must_use]
+    pub const fn col(&self) -> isize {
+        self.1
+    }
+
+    /// The four orthogonal neighbors, in [`Direction`] order. These are not
+    /// bounded; see [`Position::neighbors4_in`].
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::iter().map(move |dir| self + dir)
+    }
+
+    /// All eight neighbors, clockwise from above. These are not bounded; see
+    /// [`Position::neighbors8_in`].
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::iter().map(move |dir| self + dir)
+    }
+
+    /// The orthogonal neighbors that lie inside `grid`.
+    pub fn neighbors4_in<T>(self, grid: &Grid<T>) -> impl Iterator<Item = Self> {
+        self.neighbors4().filter(|&pos| grid.contains(pos))
+    }
+
+    /// The neighbors, diagonals included, that lie inside `grid`.
+    pub fn neighbors8_in<T>(self, grid: &Grid<T>) -> impl Iterator<Item = Self> {
+        self.neighbors8().filter(|&pos| grid.contains(pos))
+    }
+}
+
+impl Add<Direction> for Position {
+    type Output = Self;
+
+    fn add(self, dir: Direction) -> Self {
+        use Direction::{Down, Left, Right, Up};
+
+        match dir {
+            Up => Self(self.0 - 1, self.1),
+            Down => Self(self.0 + 1, self.1),
+            Left => Self(self.0, self.1 - 1),
+            Right => Self(self.0, self.1 + 1),
+        }
+    }
+}
+impl Add<Direction8> for Position {
+    type Output = Self;
+
+    fn add(self, dir: Direction8) -> Self {
+        let (dy, dx) = dir.offset();
+        Self(self.0 + dy, self.1 + dx)
+    }
+}
+impl Add<Self> for Position {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl TryFrom<Position> for (usize, usize) {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(pos: Position) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(pos.0)?, usize::try_from(pos.1)?))
+    }
+}
+
+impl TryFrom<(usize, usize)> for Position {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from((row, col): (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(Self(isize::try_from(row)?, isize::try_from(col)?))
+    }
+}
+
+/// Panics if the position is out of bounds; see [`GridExt::get_pos`] for a
+/// checked version.
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get_pos(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+/// Panics if the position is out of bounds; see [`GridExt::get_pos_mut`] for
+/// a checked version.
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_pos_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+/// Access to a [`Grid`] by [`Position`], where negative or too-large
+/// positions are handled instead of panicking.
+pub trait GridExt<T> {
+    /// Whether the position is inside the grid.
+    fn contains(&self, pos: Position) -> bool;
+
+    /// The cell at a position, or `None` if it's outside the grid.
+    fn get_pos(&self, pos: Position) -> Option<&T>;
+
+    /// The cell at a position, or `None` if it's outside the grid.
+    fn get_pos_mut(&mut self, pos: Position) -> Option<&mut T>;
+
+    /// The cell at a position on an endless grid made of copies of this one,
+    /// so any position wraps around (toroidally).
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty.
+    fn get_wrapping(&self, pos: Position) -> &T;
+}
+
+impl<T> GridExt<T> for Grid<T> {
+    fn contains(&self, pos: Position) -> bool {
+        self.get_pos(pos).is_some()
+    }
+
+    fn get_pos(&self, pos: Position) -> Option<&T> {
+        self.get(pos.0, pos.1)
+    }
+
+    fn get_pos_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.get_mut(pos.0, pos.1)
+    }
+
+    fn get_wrapping(&self, pos: Position) -> &T {
+        let wrap = |value: isize, size: usize| {
+            let size = isize::try_from(size).expect("The grid is too large");
+            value.rem_euclid(size)
+        };
+        &self[Position(wrap(pos.0, self.rows()), wrap(pos.1, self.cols()))]
+    }
+}
+
+/// Read a rectangular grid, converting each character with `TryFrom<char>`.
+///
+/// # Errors
+///
+/// On a character that can't be converted, or if the rows are not all the
+/// same length.
+pub fn parse_grid<T: TryFrom<char>>(text: &str) -> Result<Grid<T>, ParseError> {
+    parse_grid_with(text, |c| T::try_from(c).ok())
+}
+
+/// Read a rectangular grid, converting each character with `cell`, which
+/// returns `None` for characters that don't belong in the grid.
+///
+/// # Errors
+///
+/// On an unknown character, or if the rows are not all the same length.
+pub fn parse_grid_with<T>(
+    text: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut cells = Vec::new();
+    let mut cols = None;
+    for line in text.lines() {
+        let before = cells.len();
+        for (i, c) in line.char_indices() {
+            let value = cell(c).ok_or_else(|| {
+                let c = char_at(line, i);
+                ParseError::at(c, format!("unexpected character {c:?}"))
+            })?;
+            cells.push(value);
+        }
+        let len = cells.len() - before;
+        if *cols.get_or_insert(len) != len {
+            return Err(ParseError::at(line, "rows must all be the same length"));
+        }
+    }
+    Ok(Grid::from_vec(cells, cols.unwrap_or(0)))
+}
+
+/// A marker character found by [`find_marker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker<'a> {
+    /// The (row, column) of the marker in the grid.
+    pub pos: (usize, usize),
+    /// The marker in the text, so errors can point at it.
+    pub token: &'a str,
+}
+
+impl Marker<'_> {
+    /// An error about the marker, pointing at it in the text.
+    #[must_use]
+    pub fn error(&self, message: impl Display) -> ParseError {
+        ParseError::at(self.token, message)
+    }
+}
+
+/// The one `marker` character in the text, like the `S` for a start.
+///
+/// # Errors
+///
+/// If there is no marker, or more than one (pointing at the second).
+pub fn find_marker(text: &str, marker: char) -> Result<Marker<'_>, ParseError> {
+    let mut found = text.lines().enumerate().flat_map(|(y, line)| {
+        line.char_indices()
+            .filter(move |&(_, c)| c == marker)
+            .map(move |(i, _)| (y, line, i))
+    });
+    let (y, line, i) = found
+        .next()
+        .ok_or_else(|| ParseError::new(format!("expected a {marker:?}")))?;
+    if let Some((_, other, j)) = found.next() {
+        return Err(ParseError::at(
+            char_at(other, j),
+            format!("expected only one {marker:?}"),
+        ));
+    }
+    Ok(Marker {
+        pos: (y, line[..i].chars().count()),
+        token: char_at(line, i),
+    })
+}
+
+/// A terminal color for highlighting cells.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// The ANSI escape code that switches to this (bright) color.
+    #[must_use]
+    pub const fn ansi(self) -> &'static str {
+        match self {
+            Self::Red => "\x1b[91m",
+            Self::Green => "\x1b[92m",
+            Self::Yellow => "\x1b[93m",
+            Self::Blue => "\x1b[94m",
+            Self::Magenta => "\x1b[95m",
+            Self::Cyan => "\x1b[96m",
+        }
+    }
+
+    /// The ANSI escape code that goes back to the default color.
+    pub const RESET: &str = "\x1b[0m";
+}
+
+/// Draws a grid as text, one row per line, with optional highlight layers.
+/// Colors are only used if turned on, so the default is plain text that is
+/// safe to write to files and pipes.
+///
+/// ```ignore
+/// let picture = Renderer::new(&grid)
+///     .highlight(&path, Color::Yellow)
+///     .color_for(&std::io::stderr());
+/// log::debug!("\n{picture}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    layers: Vec<(&'a Grid<bool>, Color)>,
+    color: bool,
+}
+
+impl<'a, T: Display> Renderer<'a, T> {
+    /// A plain renderer for a grid.
+    #[must_use]
+    pub const fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            layers: Vec::new(),
+            color: false,
+        }
+    }
+
+    /// Show the cells set in `mask` in `color`. Where layers overlap, the one
+    /// added first wins.
+    ///
+    /// # Panics
+    ///
+    /// If the mask is not the same size as the grid.
+    #[must_use]
+    pub fn highlight(mut self, mask: &'a Grid<bool>, color: Color) -> Self {
+        assert_eq!(
+            mask.size(),
+            self.grid.size(),
+            "A highlight must be the same size as the grid"
+        );
+        self.layers.push((mask, color));
+        self
+    }
+
+    /// Turn colors on or off.
+    #[must_use]
+    pub const fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Use colors only if `stream` is a terminal, like
+    /// `color_for(&std::io::stdout())`.
+    #[must_use]
+    pub fn color_for(self, stream: &impl IsTerminal) -> Self {
+        self.color(stream.is_terminal())
+    }
+
+    /// Write the grid, ending with a newline.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails.
+    pub fn write_to(&self, out: &mut impl io::Write) -> io::Result<()> {
+        writeln!(out, "{self}")
+    }
+}
+
+/// The rows are separated by newlines, without one at the end.
+impl<T: Display> Display for Renderer<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.grid.iter_rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for (x, cell) in row.enumerate() {
+                let color = self
+                    .layers
+                    .iter()
+                    .find(|(mask, _)| mask[(y, x)])
+                    .map(|&(_, color)| color)
+                    .filter(|_| self.color);
+                match color {
+                    Some(color) => write!(f, "{}{cell}{}", color.ansi(), Color::RESET)?,
+                    None => write!(f, "{cell}")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Wall(bool);
+
+    impl TryFrom<char> for Wall {
+        type Error = ();
+
+        fn try_from(c: char) -> Result<Self, ()> {
+            match c {
+                '#' => Ok(Self(true)),
+                '.' => Ok(Self(false)),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let text = "#.\n.#\n";
+        let parsed: Grid<Wall> = parse_grid(text).unwrap();
+        assert_eq!(parsed.size(), (2, 2));
+        assert_eq!(parsed[(1, 1)], Wall(true));
+
+        let text = "#.\n.x\n";
+        let err = parse_grid::<Wall>(text).unwrap_err().locate(text);
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: unexpected character \"x\""
+        );
+
+        let text = "#.\n.##\n";
+        let err = parse_grid_with(text, Some).unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_markers() {
+        let text = "..\n☃S\n";
+        let marker = find_marker(text, 'S').unwrap();
+        assert_eq!(marker.pos, (1, 1));
+        assert_eq!(marker.token, "S");
+        let err = marker.error("misplaced").locate(text);
+        assert_eq!(err.to_string(), "line 2, column 2: misplaced");
+        assert!(find_marker(text, 'E').is_err());
+        let text = "S.\n.S\n";
+        let err = find_marker(text, 'S').unwrap_err().locate(text);
+        assert_eq!(err.position(), Some((2, 2)));
+    }
+
+    #[test]
+    fn test_renderer() {
+        let grid = parse_grid_with("ab\ncd\n", Some).unwrap();
+        let mut mask = Grid::new(2, 2);
+        mask[(0, 1)] = true;
+        let mut other = Grid::new(2, 2);
+        other[(0, 1)] = true;
+        other[(1, 0)] = true;
+
+        let renderer = Renderer::new(&grid)
+            .highlight(&mask, Color::Yellow)
+            .highlight(&other, Color::Green);
+        assert_eq!(renderer.to_string(), "ab\ncd");
+
+        let mut out = Vec::new();
+        renderer.color(true).write_to(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "a\x1b[93mb\x1b[0m\n\x1b[92mc\x1b[0md\n"
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: Grid<u8> = Grid::new(2, 3);
+        let corner = Position::new(0, 0);
+        assert_eq!(corner.neighbors4().count(), 4);
+        assert_eq!(
+            corner.neighbors4_in(&grid).collect::<Vec<_>>(),
+            [Position::new(1, 0), Position::new(0, 1)]
+        );
+        assert_eq!(
+            corner.neighbors8_in(&grid).collect::<Vec<_>>(),
+            [
+                Position::new(0, 1),
+                Position::new(1, 1),
+                Position::new(1, 0)
+            ]
+        );
+        assert_eq!(Position::new(1, 1).neighbors8_in(&grid).count(), 5);
+        assert_eq!(Position::new(5, 5).neighbors8().count(), 8);
+        assert!(Direction8::UpLeft.is_diagonal());
+        assert_eq!(
+            Position::new(1, 1) + Direction8::from(Direction::Left),
+            Position::new(1, 0)
+        );
+    }
+
+    #[test]
+    fn test_grid_ext() {
+        let mut grid = parse_grid_with("ab\ncd\n", Some).unwrap();
+        assert!(grid.contains(Position::new(1, 1)));
+        assert!(!grid.contains(Position::new(-1, 0)));
+        assert!(!grid.contains(Position::new(0, 2)));
+        assert_eq!(grid.get_pos(Position::new(1, 0)), Some(&'c'));
+        assert_eq!(grid.get_pos(Position::new(2, 0)), None);
+        *grid.get_pos_mut(Position::new(0, 1)).unwrap() = 'B';
+        assert_eq!(grid[Position::new(0, 1)], 'B');
+        assert_eq!(grid.get_wrapping(Position::new(-1, -1)), &'d');
+        assert_eq!(grid.get_wrapping(Position::new(4, 5)), &'B');
+    }
+}
@@ -0,0 +1,75 @@
+/*!
+Finding and reading puzzle input.
+
+By default, day `N` reads `input/NN.txt`. Set `AOC_INPUT_DIR` to read from a
+different directory (say, another account's inputs in CI), or read a specific
+file with [`read`]; a path of `-` reads stdin instead.
+*/
+
+use std::ffi::OsString;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The environment variable that overrides the input directory.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The directory inputs are read from when no `AOC_INPUT_DIR` is set.
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+fn dir_from(var: Option<OsString>, default: &str) -> PathBuf {
+    var.filter(|dir| !dir.is_empty())
+        .map_or_else(|| PathBuf::from(default), PathBuf::from)
+}
+
+/// The directory named by the environment variable `var`, or `default` if it
+/// is unset or empty.
+pub(crate) fn env_dir(var: &str, default: &str) -> PathBuf {
+    dir_from(std::env::var_os(var), default)
+}
+
+/// The directory inputs are read from, `AOC_INPUT_DIR` or `input`.
+#[must_use]
+pub fn input_dir() -> PathBuf {
+    env_dir(INPUT_DIR_VAR, DEFAULT_INPUT_DIR)
+}
+
+/// The conventional input file for a day, like `input/05.txt`.
+#[must_use]
+pub fn default_path(number: u32) -> PathBuf {
+    input_dir().join(format!("{number:02}.txt"))
+}
+
+/// Read an input file, or stdin if the path is `-`.
+///
+/// # Errors
+///
+/// If the file (or stdin) can't be read.
+pub fn read(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dir_from() {
+        assert_eq!(dir_from(None, "input"), Path::new("input"));
+        assert_eq!(dir_from(Some("".into()), "input"), Path::new("input"));
+        assert_eq!(
+            dir_from(Some("/ci/inputs".into()), "input"),
+            Path::new("/ci/inputs")
+        );
+    }
+
+    #[test]
+    fn test_read_missing() {
+        assert!(read(Path::new("no/such/input.txt")).is_err());
+    }
+}
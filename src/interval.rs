@@ -0,0 +1,339 @@
+/*!
+Closed integer intervals, and sets of them.
+
+An [`Interval`] includes both of its bounds, so `Interval::new(1, 4000)` is a
+rating from 1 to 4000, and it is never empty. Bounds that might cross, like
+the values past a comparison, go through [`Interval::try_new`] instead, which
+has nothing to give for an empty range. Intervals can be shifted with
+`+` and `-`, and intersected or subtracted from each other.
+
+An [`IntervalSet`] keeps its intervals sorted, and merges any that overlap or
+touch, so it can hold whatever is left after splitting ranges apart.
+
+```ignore
+let ratings = IntervalSet::from(Interval::new(1, 4000));
+let low = ratings.intersection(&Interval::new(1, 1350).into());
+assert_eq!(ratings.difference(&low).size(), 2650);
+```
+*/
+
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use num::PrimInt;
+
+/// The integers from `low` to `high`, inclusive.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    low: T,
+    high: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    /// The interval between two bounds, in either order.
+    #[must_use]
+    pub fn new(a: T, b: T) -> Self {
+        Self {
+            low: a.min(b),
+            high: a.max(b),
+        }
+    }
+
+    /// The interval from `low` to `high`, or `None` if `high` is below `low`.
+    #[must_use]
+    pub fn try_new(low: T, high: T) -> Option<Self> {
+        (low <= high).then_some(Self { low, high })
+    }
+
+    /// The smallest value in the interval.
+    #[must_use]
+    pub const fn low(&self) -> T {
+        self.low
+    }
+
+    /// The largest value in the interval.
+    #[must_use]
+    pub const fn high(&self) -> T {
+        self.high
+    }
+
+    /// The number of values in the interval.
+    #[must_use]
+    pub fn size(&self) -> T {
+        self.high - self.low + T::one()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.low <= value && value <= self.high
+    }
+
+    /// Whether the intervals have no values in common.
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.low > other.high || self.high < other.low
+    }
+
+    /// The values in both intervals, if there are any.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        (!self.is_disjoint(other)).then(|| Self {
+            low: self.low.max(other.low),
+            high: self.high.min(other.high),
+        })
+    }
+
+    /// The values in this interval but not in `other`: up to two pieces, the
+    /// part below `other` and the part above it.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> IntervalSet<T> {
+        if self.is_disjoint(other) {
+            return (*self).into();
+        }
+        let below = (self.low < other.low).then(|| Self {
+            low: self.low,
+            high: other.low - T::one(),
+        });
+        let above = (other.high < self.high).then(|| Self {
+            low: other.high + T::one(),
+            high: self.high,
+        });
+        below.into_iter().chain(above).collect()
+    }
+
+    /// Whether the two could be one interval: they overlap, or one starts
+    /// right after the other ends.
+    fn touches(&self, other: &Self) -> bool {
+        self.low <= other.high.saturating_add(T::one())
+            && other.low <= self.high.saturating_add(T::one())
+    }
+}
+
+/// Shows `low-high`, or just the value if there is only one.
+impl<T: Debug + PartialEq> Debug for Interval<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.low == self.high {
+            write!(f, "{:?}", self.low)
+        } else {
+            write!(f, "{:?}-{:?}", self.low, self.high)
+        }
+    }
+}
+
+impl<T: PrimInt> Add<T> for Interval<T> {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self {
+        Self {
+            low: self.low + rhs,
+            high: self.high + rhs,
+        }
+    }
+}
+
+impl<T: PrimInt> Sub<T> for Interval<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self {
+        Self {
+            low: self.low - rhs,
+            high: self.high - rhs,
+        }
+    }
+}
+
+impl<T: PrimInt> AddAssign<T> for Interval<T> {
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: PrimInt> SubAssign<T> for Interval<T> {
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+/// Any number of values, stored as sorted intervals that neither overlap nor
+/// touch.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The intervals, from lowest to highest.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    #[must_use]
+    pub fn size(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |total, interval| total + interval.size())
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    /// Add the values in `interval`, merging it with any it touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+        self.intervals.retain(|other| {
+            if merged.touches(other) {
+                merged = Interval {
+                    low: merged.low.min(other.low),
+                    high: merged.high.max(other.high),
+                };
+                false
+            } else {
+                true
+            }
+        });
+        let index = self
+            .intervals
+            .partition_point(|other| other.low < merged.low);
+        self.intervals.insert(index, merged);
+    }
+
+    /// The values in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    /// The values in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.iter()
+            .flat_map(|a| other.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    /// The values in this set but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        other.iter().fold(self.clone(), |remaining, b| {
+            remaining.iter().flat_map(|a| a.difference(b)).collect()
+        })
+    }
+}
+
+impl<T: Debug + PartialEq> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(u64, u64)]) -> IntervalSet<u64> {
+        intervals
+            .iter()
+            .map(|&(low, high)| Interval::new(low, high))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(7, 3);
+        assert_eq!((interval.low(), interval.high()), (3, 7));
+        assert_eq!(interval.size(), 5);
+        assert!(interval.contains(7));
+        assert!(!interval.contains(8));
+        assert_eq!(interval + 2, Interval::new(5, 9));
+        assert_eq!(interval - 3, Interval::new(0, 4));
+        assert_eq!(format!("{:?}", Interval::new(2, 2)), "2");
+        assert_eq!(Interval::try_new(3, 7), Some(interval));
+        assert_eq!(Interval::try_new(7, 7), Some(Interval::new(7, 7)));
+        assert_eq!(Interval::try_new(7, 3), None);
+        assert_eq!(
+            interval.intersection(&Interval::new(6, 10)),
+            Some(Interval::new(6, 7))
+        );
+        assert_eq!(interval.intersection(&Interval::new(8, 10)), None);
+
+        assert_eq!(
+            interval.difference(&Interval::new(4, 5)),
+            set(&[(3, 3), (6, 7)])
+        );
+        assert_eq!(interval.difference(&Interval::new(0, 5)), set(&[(6, 7)]));
+        assert!(interval.difference(&Interval::new(0, 10)).is_empty());
+    }
+
+    #[test]
+    fn test_set() {
+        let a = set(&[(10, 20), (1, 5), (6, 7), (30, 40)]);
+        assert_eq!(format!("{a:?}"), "{1-7, 10-20, 30-40}");
+        assert_eq!(a.size(), 7 + 11 + 11);
+        assert!(a.contains(15));
+        assert!(!a.contains(25));
+
+        let b = set(&[(4, 12), (35, 50)]);
+        assert_eq!(a.union(&b), set(&[(1, 20), (30, 50)]));
+        assert_eq!(a.intersection(&b), set(&[(4, 7), (10, 12), (35, 40)]));
+        assert_eq!(a.difference(&b), set(&[(1, 3), (13, 20), (30, 34)]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_bounds() {
+        // Merging and splitting at the top of the type doesn't overflow.
+        let all = Interval::new(1, u8::MAX);
+        let mut pieces = IntervalSet::from(Interval::new(u8::MAX, u8::MAX));
+        pieces.insert(Interval::new(1, 254));
+        assert_eq!(pieces, all.into());
+        assert_eq!(pieces.size(), u8::MAX);
+        assert_eq!(
+            all.difference(&Interval::new(1, 254)),
+            Interval::new(u8::MAX, u8::MAX).into()
+        );
+    }
+}
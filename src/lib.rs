@@ -5,124 +5,95 @@
 ## aoc2023 crate
 
 
-A few problems use repeated items, so those are provided here.
+Every day lives in [`days`] and implements [`Solution`] (and [`PartTwo`]), and the `aoc` binary
+runs them through the [`days::DAYS`] registry, reading files found by [`input`].
+The puzzle examples are test fixtures, checked with [`examples`], and the
+runner can report answers as JSON with [`report`].
+Input is parsed with the helpers in
+[`parse`], which report a [`ParseError`] with the line and column of bad input.
+A few problems use repeated items, so those are provided here too, like the
+grid parsing and directions in [`grid_helper`], the shortest paths in
+[`search`], the repeating states in [`cycle`], the lattice areas in
+[`polygon`], the integer ranges in [`interval`], the 3D vectors and boxes in
+[`geometry`], and the LCMs and CRT for lining up loops in [`number_theory`].
 
 */
 
-/// Helpers for 2D grid problems.
-pub mod grid_helper {
-    use core::ops::{Add, Index, IndexMut};
-
-    use derive_more::Constructor;
-    use grid::Grid;
-    use strum::EnumIter;
-
-    /// This is a direction. Can be converted to a bitflags-like u8.
-    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
-    #[repr(u8)]
-    pub enum Direction {
-        Up = 0x01,
-        Down = 0x02,
-        Left = 0x04,
-        Right = 0x08,
-    }
-
-    impl Direction {
-        /// This rotates the direction clockwise.
-        #[must_use]
-        pub const fn clockwise(&self) -> Self {
-            use Direction::{Down, Left, Right, Up};
-
-            match self {
-                Up => Right,
-                Right => Down,
-                Down => Left,
-                Left => Up,
-            }
-        }
-
-        /// This rotates the direction counter-clockwise.
-        #[must_use]
-        pub const fn counter_clockwise(&self) -> Self {
-            use Direction::{Down, Left, Right, Up};
-
-            match self {
-                Up => Left,
-                Left => Down,
-                Down => Right,
-                Right => Up,
-            }
-        }
-    }
-
-    /// This is a helper for a signed position. You can add a direction to step
-    /// in that direction. You can try convert to a classic (usize, usize)
-    /// position.
-    #[derive(Debug, Constructor, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-    pub struct Position(isize, isize);
-
-    impl Position {
-        /// The row of this position, signed.
-        #[must_use]
-        pub const fn row(&self) -> isize {
-            self.0
-        }
-        /// The column of this position, signed.
-        #[must_use]
-        pub const fn col(&self) -> isize {
-            self.1
-        }
-    }
-
-    impl Add<Direction> for Position {
-        type Output = Self;
-
-        fn add(self, dir: Direction) -> Self {
-            use Direction::{Down, Left, Right, Up};
+use core::fmt::Display;
+
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod examples;
+pub mod geometry;
+pub mod grid_helper;
+pub mod input;
+pub mod interval;
+pub mod number_theory;
+pub mod parse;
+pub mod polygon;
+pub mod report;
+pub mod scaffold;
+pub mod search;
+
+pub use parse::ParseError;
+
+/// A part's answer, as it's reported. A part that can fail returns a
+/// `Result`, and its error is reported in place of the answer.
+pub trait Answer {
+    /// The answer as text, or why there isn't one.
+    ///
+    /// # Errors
+    ///
+    /// If the part failed.
+    fn into_answer(self) -> Result<String, String>;
+}
 
-            match dir {
-                Up => Self(self.0 - 1, self.1),
-                Down => Self(self.0 + 1, self.1),
-                Left => Self(self.0, self.1 - 1),
-                Right => Self(self.0, self.1 + 1),
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn into_answer(self) -> Result<String, String> {
+                    Ok(self.to_string())
+                }
             }
-        }
-    }
-    impl Add<Self> for Position {
-        type Output = Self;
-
-        fn add(self, other: Self) -> Self {
-            Self(self.0 + other.0, self.1 + other.1)
-        }
-    }
+        )*
+    };
+}
 
-    impl TryFrom<Position> for (usize, usize) {
-        type Error = std::num::TryFromIntError;
+display_answer!(i64, u32, u64, usize, String);
 
-        fn try_from(pos: Position) -> Result<Self, Self::Error> {
-            Ok((usize::try_from(pos.0)?, usize::try_from(pos.1)?))
-        }
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map_err(|err| err.to_string())?.into_answer()
     }
+}
 
-    /// Panics if the position is out of bounds.
-    impl<T> Index<Position> for Grid<T> {
-        type Output = T;
+/// A day's puzzle solution. The input is parsed once, and then shared between
+/// both parts (see [`PartTwo`]).
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+    /// The answer to the first part.
+    type Answer1: Answer;
+
+    /// Parse the puzzle text.
+    ///
+    /// # Errors
+    ///
+    /// If the text is not valid input for this day.
+    fn parse(text: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve the first part.
+    fn part1(input: &Self::Input) -> Self::Answer1;
+}
 
-        fn index(&self, pos: Position) -> &Self::Output {
-            &self[(
-                usize::try_from(pos.0).unwrap(),
-                usize::try_from(pos.1).unwrap(),
-            )]
-        }
-    }
+/// The second part of a day's puzzle. Every day has one except day 25.
+pub trait PartTwo: Solution {
+    /// The answer to the second part.
+    type Answer2: Answer;
 
-    /// Panics if the position is out of bounds.
-    impl<T> IndexMut<Position> for Grid<T> {
-        fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
-            &mut self[(
-                usize::try_from(pos.0).unwrap(),
-                usize::try_from(pos.1).unwrap(),
-            )]
-        }
-    }
+    /// Solve the second part.
+    fn part2(input: &Self::Input) -> Self::Answer2;
 }
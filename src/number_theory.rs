@@ -0,0 +1,166 @@
+/*!
+Greatest common divisors, least common multiples, and the Chinese remainder
+theorem, for when several things loop with different periods.
+
+Everything here works on `u64`, and reports overflow instead of wrapping:
+[`lcm_all`] gives `None`, and [`crt`] gives [`CrtError::Overflow`]. The CRT
+solver handles moduli that share factors, where some sets of congruences have
+no solution at all; it says which congruence couldn't be met.
+
+```ignore
+// Loops of 3 and 4 steps, at offsets 2 and 3: both line up at step 11.
+assert_eq!(crt([(2, 3), (3, 4)]), Ok((11, 12)));
+```
+*/
+#![allow(clippy::many_single_char_names)]
+
+use core::fmt::{self, Display, Formatter};
+
+/// The greatest common divisor, with `gcd(0, 0) == 0`.
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u64`.
+#[must_use]
+pub const fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The greatest common divisor of all the values (0 if there are none).
+pub fn gcd_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(0, gcd)
+}
+
+/// The least common multiple of all the values (1 if there are none), or
+/// `None` if it doesn't fit in a `u64`.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Why a set of congruences couldn't be solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// No number is `combined` (all the congruences before this one, as an
+    /// `(offset, modulus)` pair) and `conflict` at the same time.
+    NoSolution {
+        combined: (u64, u64),
+        conflict: (u64, u64),
+    },
+    /// The combined modulus doesn't fit in a `u64`.
+    Overflow,
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSolution {
+                combined: (a, m),
+                conflict: (b, n),
+            } => write!(f, "no number is both {a} mod {m} and {b} mod {n}"),
+            Self::Overflow => write!(f, "the combined modulus overflows a u64"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solve `x = offset (mod modulus)` for every `(offset, modulus)` pair.
+///
+/// The moduli don't need to be coprime. The answer is the smallest such `x`
+/// and the modulus it repeats with (the LCM of the moduli), so every solution
+/// is `x + k * modulus`. With no congruences at all, that's `(0, 1)`.
+///
+/// # Errors
+///
+/// Returns [`CrtError::NoSolution`] if two congruences contradict each
+/// other, and [`CrtError::Overflow`] if the combined modulus is too big.
+///
+/// # Panics
+///
+/// Panics if a modulus is zero.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Result<(u64, u64), CrtError> {
+    congruences.into_iter().try_fold((0, 1), |(a, m), (b, n)| {
+        assert!(n != 0, "a modulus must not be zero");
+        let b = b % n;
+        let g = gcd(m, n);
+        // Only a difference that is a multiple of the gcd can be bridged.
+        let diff = i128::from(b) - i128::from(a);
+        if diff % i128::from(g) != 0 {
+            return Err(CrtError::NoSolution {
+                combined: (a, m),
+                conflict: (b, n),
+            });
+        }
+        let modulus = lcm(m, n).ok_or(CrtError::Overflow)?;
+        // a + m * k = b (mod n), so k = diff / g * (m / g)^-1 (mod n / g).
+        // Both factors are below n, so their product fits in a u128, and
+        // m * k is below the combined modulus.
+        let step = n / g;
+        let quotient = u128::try_from((diff / i128::from(g)).rem_euclid(step.into())).unwrap();
+        let k = quotient * u128::from(inverse(m / g, step)) % u128::from(step);
+        let x = u128::from(a) + u128::from(m) * k;
+        Ok((u64::try_from(x % u128::from(modulus)).unwrap(), modulus))
+    })
+}
+
+/// The inverse of `a` modulo `m`, by the extended Euclidean algorithm. They
+/// must be coprime.
+fn inverse(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (i128::from(a), i128::from(m));
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    u64::try_from(old_s.rem_euclid(m.into())).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+        assert_eq!(lcm_all([u64::MAX, u64::MAX]), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([]), Ok((0, 1)));
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // Moduli sharing a factor of 2.
+        assert_eq!(crt([(2, 3), (3, 4)]), Ok((11, 12)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Ok((9, 12)));
+        // Offsets are reduced first.
+        assert_eq!(crt([(7, 5)]), Ok((2, 5)));
+        assert_eq!(
+            crt([(1, 4), (2, 6)]),
+            Err(CrtError::NoSolution {
+                combined: (1, 4),
+                conflict: (2, 6)
+            })
+        );
+        assert_eq!(crt([(0, u64::MAX), (1, 2)]), Err(CrtError::Overflow));
+        let big = (1 << 61) - 1;
+        assert_eq!(crt([(3, big), (5, 7)]), Ok((3 + 2 * big, 7 * big)));
+        assert_eq!(crt([(big - 1, big), (0, 8)]), Ok((7 * big - 1, 8 * big)));
+    }
+}
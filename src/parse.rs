@@ -0,0 +1,192 @@
+/*!
+Parsing errors and small helpers for reading puzzle input.
+
+A [`ParseError`] can point at the piece of input it failed on. Parsers work
+with slices of the input text, so the error just remembers where that slice
+lives; once the whole text is known again, [`ParseError::locate`] turns it into
+a line and column. The [`days`](crate::days) registry does that for every day,
+so a parser only needs to say *which* token was bad.
+*/
+
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+use core::str::FromStr;
+
+/// An error from parsing puzzle input, optionally with the line and column
+/// (both 1-based) of the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    /// The memory span of the offending text, until it is located.
+    span: Option<Range<usize>>,
+    position: Option<(usize, usize)>,
+}
+
+impl ParseError {
+    /// An error without any location.
+    #[must_use]
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            span: None,
+            position: None,
+        }
+    }
+
+    /// An error pointing at `token`, which should be a slice of the input.
+    #[must_use]
+    pub fn at(token: &str, message: impl Display) -> Self {
+        let start = token.as_ptr().addr();
+        Self {
+            span: Some(start..start + token.len()),
+            ..Self::new(message)
+        }
+    }
+
+    /// Work out the line and column, if the offending token is part of `text`.
+    #[must_use]
+    pub fn locate(mut self, text: &str) -> Self {
+        let start = text.as_ptr().addr();
+        if let Some(span) = &self.span
+            && start <= span.start
+            && span.end <= start + text.len()
+        {
+            let before = &text[..span.start - start];
+            let line = before.matches('\n').count() + 1;
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            let column = before[line_start..].chars().count() + 1;
+            self.position = Some((line, column));
+            self.span = None;
+        }
+        self
+    }
+
+    /// The message, without the location.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The line and column (1-based) of the error, once located.
+    #[must_use]
+    pub const fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(f, "line {line}, column {column}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a number, pointing at it if it's malformed.
+///
+/// # Errors
+///
+/// If `token` is not a valid `T`.
+pub fn number<T: FromStr>(token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(token, format!("expected a number, found {token:?}")))
+}
+
+/// Parse every whitespace separated number in `text`.
+///
+/// # Errors
+///
+/// If any of them is not a valid `T`.
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(number).collect()
+}
+
+/// Like [`str::split_once`], but an error if `delimiter` is missing.
+///
+/// # Errors
+///
+/// If `text` does not contain `delimiter`.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(text, format!("expected {delimiter:?}")))
+}
+
+/// Like [`str::strip_prefix`], but an error if `prefix` is missing.
+///
+/// # Errors
+///
+/// If `text` does not start with `prefix`.
+pub fn strip_prefix<'a>(text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(text, format!("expected {prefix:?}")))
+}
+
+/// The next item of `iter`, or an error naming what was `expected` there.
+///
+/// # Errors
+///
+/// If the iterator is exhausted.
+pub fn expect_next<'a>(
+    iter: &mut impl Iterator<Item = &'a str>,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    iter.next()
+        .ok_or_else(|| ParseError::new(format!("expected {expected}, found end of input")))
+}
+
+/// The single character at byte `index` of `line`, as a slice for
+/// [`ParseError::at`].
+#[must_use]
+pub fn char_at(line: &str, index: usize) -> &str {
+    let len = line[index..].chars().next().map_or(0, char::len_utf8);
+    &line[index..index + len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+1 2 3
+4 x 6
+";
+
+    #[test]
+    fn test_locate() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let err = numbers::<u32>(line).unwrap_err().locate(INPUT);
+        assert_eq!(err.position(), Some((2, 3)));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_unlocated() {
+        let err = split_once("a b", ":").unwrap_err();
+        assert_eq!(err.position(), None);
+        let err = err.locate(INPUT);
+        assert_eq!(err.position(), None);
+        assert_eq!(err.to_string(), "expected \":\"");
+        assert_eq!(
+            strip_prefix(INPUT, "Game ")
+                .unwrap_err()
+                .locate(INPUT)
+                .position(),
+            Some((1, 1))
+        );
+    }
+
+    #[test]
+    fn test_char_at() {
+        let line = "ab☃d";
+        assert_eq!(char_at(line, 2), "☃");
+        let err = ParseError::at(char_at(line, 5), "bad").locate(line);
+        assert_eq!(err.position(), Some((1, 4)));
+    }
+}
@@ -0,0 +1,150 @@
+/*!
+Polygons on the integer lattice, traced out by a walk of [`Direction`] and
+length steps, like a dig plan or a loop of pipe.
+
+The area comes from the shoelace formula, and Pick's theorem
+(`area = interior + boundary / 2 - 1`) turns that into a count of the lattice
+points inside, or inside and on the boundary.
+
+```ignore
+let polygon = Polygon::from_steps([(Right, 2), (Down, 2), (Left, 2), (Up, 2)]);
+assert_eq!(polygon.area(), 4);
+assert_eq!(polygon.interior_points(), 1);
+assert_eq!(polygon.lattice_points(), 9);
+```
+*/
+
+use itertools::Itertools;
+
+use crate::grid_helper::{Direction, Direction8};
+
+/// A closed polygon with its corners on the lattice, as (row, column) pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<(i64, i64)>,
+    perimeter: i64,
+}
+
+impl Polygon {
+    /// Walk the steps from the origin, one vertex per step. Lengths should not
+    /// be negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the walk does not end back at the origin; use
+    /// [`Polygon::try_from_steps`] for steps that might not.
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, i64)>) -> Self {
+        Self::try_from_steps(steps).expect("the steps must close the polygon")
+    }
+
+    /// Walk the steps from the origin like [`Polygon::from_steps`], or `None`
+    /// if the walk does not end back at the origin.
+    pub fn try_from_steps(steps: impl IntoIterator<Item = (Direction, i64)>) -> Option<Self> {
+        let mut vertices = Vec::new();
+        let mut perimeter = 0;
+        let mut position = (0, 0);
+        for (dir, len) in steps {
+            let (dy, dx) = Direction8::from(dir).offset();
+            position = (position.0 + dy as i64 * len, position.1 + dx as i64 * len);
+            perimeter += len;
+            vertices.push(position);
+        }
+        (position == (0, 0)).then_some(Self {
+            vertices,
+            perimeter,
+        })
+    }
+
+    /// The corners visited, ending at the origin.
+    #[must_use]
+    pub fn vertices(&self) -> &[(i64, i64)] {
+        &self.vertices
+    }
+
+    /// The length of the boundary, which is also the number of lattice
+    /// points on it.
+    #[must_use]
+    pub const fn perimeter(&self) -> i64 {
+        self.perimeter
+    }
+
+    /// The area by the shoelace formula: positive if the walk goes clockwise
+    /// as drawn (rows counting down the page), negative if counter-clockwise.
+    #[must_use]
+    pub fn signed_area(&self) -> i64 {
+        // The steps are all horizontal or vertical, so the sum is even.
+        let twice: i64 = self
+            .vertices
+            .iter()
+            .circular_tuple_windows()
+            .map(|(&(y0, x0), &(y1, x1))| x0 * y1 - x1 * y0)
+            .sum();
+        twice / 2
+    }
+
+    /// The enclosed area, whichever way the walk went.
+    #[must_use]
+    pub fn area(&self) -> i64 {
+        self.signed_area().abs()
+    }
+
+    /// The number of lattice points strictly inside, by Pick's theorem.
+    #[must_use]
+    pub fn interior_points(&self) -> i64 {
+        self.area() - self.perimeter / 2 + 1
+    }
+
+    /// The number of lattice points inside or on the boundary.
+    #[must_use]
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.perimeter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Direction::{Down, Left, Right, Up};
+
+    #[test]
+    fn test_square() {
+        let polygon = Polygon::from_steps([(Right, 2), (Down, 2), (Left, 2), (Up, 2)]);
+        assert_eq!(polygon.vertices(), [(0, 2), (2, 2), (2, 0), (0, 0)]);
+        assert_eq!(polygon.perimeter(), 8);
+        assert_eq!(polygon.signed_area(), 4);
+        assert_eq!(polygon.interior_points(), 1);
+        assert_eq!(polygon.lattice_points(), 9);
+
+        let reversed = Polygon::from_steps([(Down, 2), (Right, 2), (Up, 2), (Left, 2)]);
+        assert_eq!(reversed.signed_area(), -4);
+        assert_eq!(reversed.interior_points(), 1);
+    }
+
+    #[test]
+    fn test_l_shape() {
+        // ###
+        // #.#
+        // #.###
+        // #...#
+        // #####
+        let polygon = Polygon::from_steps([
+            (Right, 2),
+            (Down, 2),
+            (Right, 2),
+            (Down, 2),
+            (Left, 4),
+            (Up, 4),
+        ]);
+        assert_eq!(polygon.area(), 12);
+        assert_eq!(polygon.perimeter(), 16);
+        assert_eq!(polygon.interior_points(), 5);
+        assert_eq!(polygon.lattice_points(), 21);
+    }
+
+    #[test]
+    #[should_panic(expected = "close the polygon")]
+    fn test_open() {
+        assert_eq!(Polygon::try_from_steps([(Right, 2), (Down, 2)]), None);
+        Polygon::from_steps([(Right, 2), (Down, 2)]);
+    }
+}
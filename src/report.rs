@@ -0,0 +1,89 @@
+/*!
+Machine-readable results, one JSON object per line (JSON Lines):
+
+```json
+{"day":"05","part":1,"answer":"35","elapsed":18000}
+{"day":"08","part":1,"error":"there is no start node"}
+```
+
+The answer is always a string, since days answer with different types, and
+`elapsed` is in nanoseconds, like a [bench baseline](crate::bench). A part
+with no answer gets a [`Failure`] line in its place, so the other days still
+report.
+*/
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// The answer to one part of a day, with how long it took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record<'a> {
+    /// The registry name of the day, like `07-trait`.
+    pub day: &'a str,
+    pub part: u8,
+    pub answer: &'a str,
+    /// The time taken, in nanoseconds.
+    #[serde(serialize_with = "nanos")]
+    pub elapsed: Duration,
+}
+
+fn nanos<S: serde::Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(u64::try_from(time.as_nanos()).unwrap_or(u64::MAX))
+}
+
+/// Displays as a single line of JSON.
+impl fmt::Display for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Why one part of a day has no answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Failure<'a> {
+    /// The registry name of the day, like `07-trait`.
+    pub day: &'a str,
+    pub part: u8,
+    pub error: &'a str,
+}
+
+/// Displays as a single line of JSON.
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let record = Record {
+            day: "07-trait",
+            part: 2,
+            answer: "say \"hi\"",
+            elapsed: Duration::from_micros(18),
+        };
+        assert_eq!(
+            record.to_string(),
+            r#"{"day":"07-trait","part":2,"answer":"say \"hi\"","elapsed":18000}"#
+        );
+    }
+
+    #[test]
+    fn test_failure_to_json() {
+        let failure = Failure {
+            day: "08",
+            part: 1,
+            error: "panicked: oops",
+        };
+        assert_eq!(
+            failure.to_string(),
+            r#"{"day":"08","part":1,"error":"panicked: oops"}"#
+        );
+    }
+}
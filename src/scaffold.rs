@@ -0,0 +1,238 @@
+/*!
+Generating a new day from `template/main.rs`.
+
+The template's placeholders (`Day X`, `day/X`, and the `DayXX` struct) are
+filled in, and the new module is added to `src/days.rs`, both as a `pub mod`
+and as an entry in [`DAYS`](crate::days::DAYS). Alternate implementations get
+a variant name, like `07-trait` in `day07_trait.rs`.
+
+The template's tests check the day's [examples](crate::examples), so a new day
+also gets an empty `examples/NN/example.txt` to paste the puzzle's example
+into, with an `example.toml` for its answers.
+*/
+
+/// The answers file written next to a new example, with nothing checked yet.
+pub const EXAMPLE_ANSWERS: &str = "\
+# The answers to example.txt, checked by `cargo test` once filled in.
+# part1 = 0
+# part2 = 0
+";
+
+/// The names a new day is known by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    /// The puzzle day, 1-25.
+    pub number: u32,
+    /// The puzzle title, used in the module doc.
+    pub title: String,
+    /// The variant suffix for alternate implementations, like `trait`.
+    pub variant: Option<String>,
+}
+
+impl NewDay {
+    /// A new day, checking the number and variant.
+    ///
+    /// # Errors
+    ///
+    /// If the number is not 1-25, or the variant is not lowercase letters,
+    /// digits, and dashes.
+    pub fn new(number: u32, title: &str, variant: Option<&str>) -> Result<Self, String> {
+        if !(1..=25).contains(&number) {
+            return Err(format!("There is no day {number}"));
+        }
+        if let Some(variant) = variant
+            && (variant.is_empty()
+                || !variant
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'))
+        {
+            return Err(format!(
+                "The variant {variant:?} should be lowercase letters, digits, and dashes"
+            ));
+        }
+        Ok(Self {
+            number,
+            title: title.to_string(),
+            variant: variant.map(ToString::to_string),
+        })
+    }
+
+    /// The registry name, like `07` or `07-trait`.
+    #[must_use]
+    pub fn name(&self) -> String {
+        let number = self.number;
+        self.variant.as_ref().map_or_else(
+            || format!("{number:02}"),
+            |variant| format!("{number:02}-{variant}"),
+        )
+    }
+
+    /// The module name, like `day07` or `day07_trait`.
+    #[must_use]
+    pub fn module(&self) -> String {
+        format!("day{}", self.name().replace('-', "_"))
+    }
+
+    /// The solution struct name, like `Day07` or `Day07Trait`.
+    #[must_use]
+    pub fn struct_name(&self) -> String {
+        let mut name = format!("Day{:02}", self.number);
+        for word in self.variant.iter().flat_map(|v| v.split('-')) {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                name.push(first.to_ascii_uppercase());
+                name.extend(chars);
+            }
+        }
+        name
+    }
+
+    /// The path of the new module, relative to the crate root.
+    #[must_use]
+    pub fn path(&self) -> String {
+        format!("src/days/{}.rs", self.module())
+    }
+
+    /// The example input for this day's tests, relative to the crate root.
+    /// Variants share it with the other implementations of the day.
+    #[must_use]
+    pub fn example_path(&self) -> String {
+        format!("examples/{:02}/example.txt", self.number)
+    }
+
+    /// Fill in the template.
+    #[must_use]
+    pub fn render(&self, template: &str) -> String {
+        let number = self.number;
+        template
+            .replace("Day X: ...", &format!("Day {number}: {}", self.title))
+            .replace("day/X>", &format!("day/{number}>"))
+            .replace("DayXX, X)", &format!("DayXX, {number})"))
+            .replace("DayXX", &self.struct_name())
+    }
+
+    /// Add the module and its registry entry to the text of `src/days.rs`.
+    ///
+    /// # Errors
+    ///
+    /// If the module is already registered, or the file doesn't look like
+    /// `src/days.rs`.
+    pub fn register(&self, days: &str) -> Result<String, String> {
+        let module = self.module();
+        let mod_line = format!("pub mod {module};");
+        if days.lines().any(|line| line == mod_line) {
+            return Err(format!("{module} is already registered"));
+        }
+        let mut lines: Vec<String> = days.lines().map(ToString::to_string).collect();
+
+        // Modules are kept in order.
+        let mods: Vec<usize> = (0..lines.len())
+            .filter(|&i| lines[i].starts_with("pub mod day"))
+            .collect();
+        let &last_mod = mods.last().ok_or("No day modules found")?;
+        let mod_at = mods
+            .iter()
+            .copied()
+            .find(|&i| lines[i] > mod_line)
+            .unwrap_or(last_mod + 1);
+        lines.insert(mod_at, mod_line);
+
+        // Days go after every entry with the same or a lower number.
+        let table = lines
+            .iter()
+            .position(|line| line.starts_with("pub const DAYS"))
+            .ok_or("No DAYS table found")?;
+        let mut day_at = table + 1;
+        for (i, line) in lines.iter().enumerate().skip(table + 1) {
+            if line.starts_with(']') {
+                break;
+            }
+            let number = line
+                .split_once(">(")
+                .and_then(|(_, rest)| rest.split_once(','))
+                .and_then(|(number, _)| number.parse::<u32>().ok());
+            if number.is_some_and(|number| number <= self.number) {
+                day_at = i + 1;
+            }
+        }
+        let entry = format!(
+            "    Day::new::<{module}::{}>({}, \"{}\"),",
+            self.struct_name(),
+            self.number,
+            self.name()
+        );
+        lines.insert(day_at, entry);
+
+        Ok(lines.join("\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "\
+pub mod day01;
+pub mod day07;
+pub mod day09;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1, \"01\"),
+    Day::new::<day07::Day07>(7, \"07\"),
+    Day::new::<day09::Day09>(9, \"09\"),
+];
+";
+
+    #[test]
+    fn test_names() {
+        let day = NewDay::new(7, "Camel Cards", Some("trait")).unwrap();
+        assert_eq!(day.name(), "07-trait");
+        assert_eq!(day.module(), "day07_trait");
+        assert_eq!(day.struct_name(), "Day07Trait");
+        assert_eq!(day.path(), "src/days/day07_trait.rs");
+        assert_eq!(
+            NewDay::new(3, "", Some("two-pass")).unwrap().struct_name(),
+            "Day03TwoPass"
+        );
+        assert!(NewDay::new(26, "", None).is_err());
+        assert!(NewDay::new(1, "", Some("Bad")).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let day = NewDay::new(8, "Haunted Wasteland", None).unwrap();
+        let text =
+            day.render("# 2023 Day X: ...\n<https://adventofcode.com/2023/day/X>\nDayXX::parse");
+        assert_eq!(
+            text,
+            "# 2023 Day 8: Haunted Wasteland\n<https://adventofcode.com/2023/day/8>\nDay08::parse"
+        );
+        assert_eq!(
+            day.render("crate::example_tests!(DayXX, X);"),
+            "crate::example_tests!(Day08, 8);"
+        );
+        assert_eq!(day.example_path(), "examples/08/example.txt");
+    }
+
+    #[test]
+    fn test_register() {
+        let day = NewDay::new(8, "", None).unwrap();
+        let text = day.register(DAYS).unwrap();
+        assert!(text.contains("pub mod day07;\npub mod day08;\npub mod day09;"));
+        assert!(text.contains(
+            "(7, \"07\"),\n    Day::new::<day08::Day08>(8, \"08\"),\n    Day::new::<day09"
+        ));
+
+        let day = NewDay::new(7, "", Some("alt")).unwrap();
+        let text = day.register(DAYS).unwrap();
+        assert!(text.contains("pub mod day07;\npub mod day07_alt;\npub mod day09;"));
+        assert!(text.contains("(7, \"07\"),\n    Day::new::<day07_alt::Day07Alt>(7, \"07-alt\"),"));
+
+        let day = NewDay::new(12, "", None).unwrap();
+        let text = day.register(DAYS).unwrap();
+        assert!(text.contains("pub mod day09;\npub mod day12;\n\n"));
+        assert!(text.contains("(9, \"09\"),\n    Day::new::<day12::Day12>(12, \"12\"),\n];"));
+
+        assert!(NewDay::new(9, "", None).unwrap().register(DAYS).is_err());
+    }
+}
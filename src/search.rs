@@ -0,0 +1,151 @@
+/*!
+Shortest paths over any kind of state, with Dijkstra's algorithm or A*.
+
+A state is anything hashable: a position, or a position with a heading and
+a run length, and so on. The search asks a closure for the successors of each
+state (with the cost of each step), and stops at the first state that passes
+the goal check, returning the total cost and every state along the way.
+
+```ignore
+let path = dijkstra(start, |&pos| moves(pos), |&pos| pos == goal)?;
+println!("{} steps costing {}", path.states.len() - 1, path.cost);
+```
+*/
+
+use core::cmp::Reverse;
+use core::hash::Hash;
+use core::ops::Add;
+use std::collections::BinaryHeap;
+
+use indexmap::IndexMap;
+use indexmap::map::Entry;
+
+/// A cheapest path: its total cost, and the states from start to goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// The cheapest path from `start` to a state where `success` is true, using
+/// Dijkstra's algorithm.
+///
+/// Step costs must not be negative. Returns `None` if no goal can be reached.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    success: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// The cheapest path from `start` to a state where `success` is true, using A*.
+///
+/// The `heuristic` estimates the remaining cost from a state, and must never
+/// overestimate it (a zero heuristic is Dijkstra's algorithm). Returns `None`
+/// if no goal can be reached.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut success: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Every state seen so far, with its parent's index and the best cost to
+    // reach it. The heap holds (estimated total, cost, index) and may have
+    // stale entries, which are skipped when their cost is beaten.
+    let mut seen: IndexMap<S, (usize, C)> = IndexMap::new();
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&start), C::default(), 0)));
+    seen.insert(start, (usize::MAX, C::default()));
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let (state, &(_, best)) = seen.get_index(index)?;
+        if cost > best {
+            continue;
+        }
+        if success(state) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&seen, index),
+            });
+        }
+        let state = state.clone();
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let (estimate, next_index) = match seen.entry(next) {
+                Entry::Vacant(entry) => {
+                    let estimate = heuristic(entry.key());
+                    let next_index = entry.index();
+                    entry.insert((index, next_cost));
+                    (estimate, next_index)
+                }
+                Entry::Occupied(mut entry) if next_cost < entry.get().1 => {
+                    let estimate = heuristic(entry.key());
+                    entry.insert((index, next_cost));
+                    (estimate, entry.index())
+                }
+                Entry::Occupied(_) => continue,
+            };
+            heap.push(Reverse((estimate + next_cost, next_cost, next_index)));
+        }
+    }
+    None
+}
+
+/// Follow the parents back from `index` to the start.
+fn reconstruct<S: Clone, C>(seen: &IndexMap<S, (usize, C)>, mut index: usize) -> Vec<S> {
+    let mut states = Vec::new();
+    while let Some((state, &(parent, _))) = seen.get_index(index) {
+        states.push(state.clone());
+        index = parent;
+    }
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph: 0 -> 1 -> 3 is cheaper than 0 -> 3.
+    fn edges(node: u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 4), (3, 10)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra(0, |&n| edges(n), |&n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.states, [0, 1, 3]);
+
+        let path = dijkstra(0, |&n| edges(n), |&n| n == 0).unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states, [0]);
+
+        assert!(dijkstra(1, |&n| edges(n), |&n| n == 2).is_none());
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walk a line from 0 to 10, where each step costs 1.
+        let steps = |&n: &i32| [(n - 1, 1), (n + 1, 1)];
+        let path = astar(0, steps, |&n: &i32| (10 - n).abs(), |&n| n == 10).unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states, (0..=10).collect::<Vec<_>>());
+    }
+}
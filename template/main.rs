@@ -1,45 +1,40 @@
 /*!
-# 2023 Day X - ...
+# 2023 Day X: ...
 
 <https://adventofcode.com/2023/day/X>
 
 */
 
+use crate::{ParseError, PartTwo, Solution};
+
 type Num = i64;
 
-fn compute1(text: &str) -> Num {
-    0
-}
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<String>;
+    type Answer1 = Num;
 
-fn compute2(text: &str) -> Num {
-    0
+    fn parse(text: &str) -> Result<Self::Input, ParseError> {
+        Ok(text.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Num {
+        0
+    }
 }
 
-fn main() {
-    let text = std::fs::read_to_string("input/15.txt").unwrap();
-    let result = compute1(&text);
-    println!("First = {result}");
+impl PartTwo for DayXX {
+    type Answer2 = Num;
 
-    let result = compute2(&text);
-    println!("Second = {result}");
+    fn part2(_input: &Self::Input) -> Num {
+        0
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "\
-";
-
-    #[test]
-    fn test_first() {
-        let result = compute1(INPUT);
-        assert_eq!(result, 0);
-    }
-
-    #[test]
-    fn test_second() {
-        let result = compute2(INPUT);
-        assert_eq!(result, 0);
-    }
+    crate::example_tests!(DayXX, X);
 }